@@ -0,0 +1,492 @@
+//! Opt-in TTL cache for project lookups.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Deref;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::client::VercelClient;
+use crate::models::{
+    DeployHook, Deployment, EnvBulkResult, EnvVar, EnvVarInput, NewProject, PaginatedResponse,
+    Project, ProjectProtection, ProjectUpdate, ProtectionBypass, ProtectionUpdate,
+};
+use serde_json::Value;
+
+/// How long a page of `list_projects_page` stays cached.
+const PROJECT_LIST_TTL: Duration = Duration::from_secs(60);
+
+/// How long a `get_project` result stays cached.
+const PROJECT_TTL: Duration = Duration::from_secs(30);
+
+/// Map whose entries expire `ttl` after insertion.
+pub struct Cache<K, V> {
+    ttl: Duration,
+    entries: HashMap<K, (V, Instant)>,
+}
+
+impl<K: Eq + Hash, V: Clone> Cache<K, V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Get a live entry, dropping it if it has expired.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        match self.entries.get(key) {
+            Some((value, expires)) if Instant::now() < *expires => Some(value.clone()),
+            Some(_) => {
+                self.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.entries.insert(key, (value, Instant::now() + self.ttl));
+    }
+
+    /// Keep only entries for which `keep` returns true.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &V) -> bool) {
+        self.entries.retain(|key, (value, _)| keep(key, value));
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Key for a cached project listing page: team scope, limit, and cursor.
+type PageKey = (Option<String>, Option<i32>, Option<i64>);
+
+/// Key for a cached project: team scope and the ID or name it was fetched by.
+type ProjectKey = (Option<String>, String);
+
+/// `VercelClient` that serves project listings and lookups from a short-lived
+/// cache. Calls that change a project, including its deployments, domains,
+/// environment, hooks, and members, drop its cached entries; every other
+/// method passes straight through to the wrapped client.
+///
+/// A new client method that changes anything `get_project` returns needs a
+/// wrapper here, or lookups will serve the old state until the entry expires.
+pub struct CachingVercelClient {
+    client: VercelClient,
+    enabled: bool,
+    project_pages: Mutex<Cache<PageKey, PaginatedResponse<Project>>>,
+    projects: Mutex<Cache<ProjectKey, Project>>,
+}
+
+impl CachingVercelClient {
+    /// Wrap `client`; with `enabled` false every call goes to the API.
+    pub fn new(client: VercelClient, enabled: bool) -> Self {
+        Self {
+            client,
+            enabled,
+            project_pages: Mutex::new(Cache::new(PROJECT_LIST_TTL)),
+            projects: Mutex::new(Cache::new(PROJECT_TTL)),
+        }
+    }
+
+    /// Replace the access token, dropping everything cached under the old one.
+    pub fn set_token(&self, token: String) -> bool {
+        let changed = self.client.set_token(token);
+        if changed {
+            self.invalidate_all();
+        }
+        changed
+    }
+
+    /// List a single page of projects, cached for 60 seconds.
+    pub async fn list_projects_page(
+        &self,
+        limit: Option<i32>,
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> Result<PaginatedResponse<Project>> {
+        if !self.enabled {
+            return self.client.list_projects_page(limit, until, team_id).await;
+        }
+
+        let key = (team_id.map(str::to_string), limit, until);
+        if let Some(page) = self.lock_pages().get(&key) {
+            return Ok(page);
+        }
+        let page = self
+            .client
+            .list_projects_page(limit, until, team_id)
+            .await?;
+        self.lock_pages().insert(key, page.clone());
+        Ok(page)
+    }
+
+    /// Get a project by ID or name, cached for 30 seconds.
+    pub async fn get_project(&self, project_id: &str, team_id: Option<&str>) -> Result<Project> {
+        if !self.enabled {
+            return self.client.get_project(project_id, team_id).await;
+        }
+
+        let key = (team_id.map(str::to_string), project_id.to_string());
+        if let Some(project) = self.lock_projects().get(&key) {
+            return Ok(project);
+        }
+        let project = self.client.get_project(project_id, team_id).await?;
+        self.lock_projects().insert(key, project.clone());
+        Ok(project)
+    }
+
+    pub async fn create_project(
+        &self,
+        project: &NewProject,
+        team_id: Option<&str>,
+    ) -> Result<Project> {
+        let result = self.client.create_project(project, team_id).await;
+        self.lock_pages().clear();
+        result
+    }
+
+    pub async fn update_project(
+        &self,
+        project_id: &str,
+        update: &ProjectUpdate,
+        team_id: Option<&str>,
+    ) -> Result<Project> {
+        let result = self
+            .client
+            .update_project(project_id, update, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        if let Ok(project) = &result {
+            // A rename leaves entries cached under the old name.
+            self.invalidate_project(&project.id);
+        }
+        result
+    }
+
+    pub async fn update_protection(
+        &self,
+        project_id: &str,
+        update: &ProtectionUpdate,
+        team_id: Option<&str>,
+    ) -> Result<ProjectProtection> {
+        let result = self
+            .client
+            .update_protection(project_id, update, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn generate_protection_bypass(
+        &self,
+        project_id: &str,
+        replace: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<HashMap<String, ProtectionBypass>> {
+        let result = self
+            .client
+            .generate_protection_bypass(project_id, replace, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn revoke_protection_bypass(
+        &self,
+        project_id: &str,
+        secret: &str,
+        team_id: Option<&str>,
+    ) -> Result<HashMap<String, ProtectionBypass>> {
+        let result = self
+            .client
+            .revoke_protection_bypass(project_id, secret, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn set_crons_enabled(
+        &self,
+        project_id: &str,
+        enabled: bool,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let result = self
+            .client
+            .set_crons_enabled(project_id, enabled, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn pause_project(&self, project_id: &str, team_id: Option<&str>) -> Result<()> {
+        let result = self.client.pause_project(project_id, team_id).await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn unpause_project(&self, project_id: &str, team_id: Option<&str>) -> Result<()> {
+        let result = self.client.unpause_project(project_id, team_id).await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn delete_project(&self, project_id: &str, team_id: Option<&str>) -> Result<()> {
+        let result = self.client.delete_project(project_id, team_id).await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn add_project_member(
+        &self,
+        project_id: &str,
+        uid: &str,
+        role: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let result = self
+            .client
+            .add_project_member(project_id, uid, role, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn remove_project_member(
+        &self,
+        project_id: &str,
+        uid: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let result = self
+            .client
+            .remove_project_member(project_id, uid, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn promote_deployment(
+        &self,
+        project_id: &str,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let result = self
+            .client
+            .promote_deployment(project_id, deployment_id, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn cancel_deployment(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Deployment> {
+        let result = self.client.cancel_deployment(deployment_id, team_id).await;
+        self.invalidate_all();
+        result
+    }
+
+    pub async fn delete_deployment(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let result = self.client.delete_deployment(deployment_id, team_id).await;
+        self.invalidate_all();
+        result
+    }
+
+    pub async fn redeploy(
+        &self,
+        deployment_id: &str,
+        target: Option<&str>,
+        skip_build_cache: bool,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let result = self
+            .client
+            .redeploy(deployment_id, target, skip_build_cache, team_id)
+            .await;
+        self.invalidate_all();
+        result
+    }
+
+    pub async fn trigger_deploy_hook(&self, hook_url: &str) -> Result<Value> {
+        let result = self.client.trigger_deploy_hook(hook_url).await;
+        self.invalidate_all();
+        result
+    }
+
+    pub async fn create_deploy_hook(
+        &self,
+        project_id: &str,
+        name: &str,
+        git_ref: &str,
+        team_id: Option<&str>,
+    ) -> Result<DeployHook> {
+        let result = self
+            .client
+            .create_deploy_hook(project_id, name, git_ref, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn delete_deploy_hook(
+        &self,
+        project_id: &str,
+        hook_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let result = self
+            .client
+            .delete_deploy_hook(project_id, hook_id, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn set_env_var(
+        &self,
+        project_id: &str,
+        var: &EnvVarInput,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let result = self.client.set_env_var(project_id, var, team_id).await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn bulk_set_env_vars(
+        &self,
+        project_id: &str,
+        vars: &[EnvVarInput],
+        upsert: bool,
+        team_id: Option<&str>,
+    ) -> Result<EnvBulkResult> {
+        let result = self
+            .client
+            .bulk_set_env_vars(project_id, vars, upsert, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn update_env_var(
+        &self,
+        project_id: &str,
+        env_id: &str,
+        value: &str,
+        target: Option<&[String]>,
+        team_id: Option<&str>,
+    ) -> Result<EnvVar> {
+        let result = self
+            .client
+            .update_env_var(project_id, env_id, value, target, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn delete_env_var(
+        &self,
+        project_id: &str,
+        env_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let result = self
+            .client
+            .delete_env_var(project_id, env_id, team_id)
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn add_domain(
+        &self,
+        project_id: &str,
+        domain: &str,
+        redirect: Option<&str>,
+        redirect_status_code: Option<i64>,
+        git_branch: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let result = self
+            .client
+            .add_domain(
+                project_id,
+                domain,
+                redirect,
+                redirect_status_code,
+                git_branch,
+                team_id,
+            )
+            .await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn remove_domain(
+        &self,
+        project_id: &str,
+        domain: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let result = self.client.remove_domain(project_id, domain, team_id).await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    pub async fn verify_domain(
+        &self,
+        project_id: &str,
+        domain: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let result = self.client.verify_domain(project_id, domain, team_id).await;
+        self.invalidate_project(project_id);
+        result
+    }
+
+    /// Drop cached entries for a project, whether it was fetched by ID or
+    /// name, along with every cached listing page.
+    ///
+    /// Runs even when the change failed: the request may have been applied
+    /// before the error was reported.
+    fn invalidate_project(&self, project_id: &str) {
+        self.lock_projects().retain(|(_, key), project| {
+            key != project_id && project.id != project_id && project.name != project_id
+        });
+        self.lock_pages().clear();
+    }
+
+    /// Drop everything cached: after a token change, or a change made through
+    /// a deployment or hook whose project is not known up front.
+    fn invalidate_all(&self) {
+        self.lock_projects().clear();
+        self.lock_pages().clear();
+    }
+
+    fn lock_pages(&self) -> std::sync::MutexGuard<'_, Cache<PageKey, PaginatedResponse<Project>>> {
+        self.project_pages.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_projects(&self) -> std::sync::MutexGuard<'_, Cache<ProjectKey, Project>> {
+        self.projects.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Deref for CachingVercelClient {
+    type Target = VercelClient;
+
+    fn deref(&self) -> &VercelClient {
+        &self.client
+    }
+}
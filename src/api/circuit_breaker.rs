@@ -0,0 +1,146 @@
+//! Circuit breaker that stops sending requests to a degraded API.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Thresholds for [`CircuitBreaker`].
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Server errors within `window` that open the circuit; zero disables the breaker.
+    pub failure_threshold: u32,
+    /// Rolling window in which failures are counted.
+    pub window: Duration,
+    /// How long the circuit stays open before a probe request is let through.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            window: Duration::from_secs(30),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests fail fast until `until`.
+    Open { until: Instant },
+    /// One probe request, sent at `since`, decides whether to close again.
+    HalfOpen { since: Instant },
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: CircuitState,
+    /// Times of recent failures while closed, oldest first.
+    failures: VecDeque<Instant>,
+}
+
+/// Tracks server errors and fails requests fast once they pile up.
+///
+/// After `failure_threshold` failures within `window` the circuit opens and
+/// requests are rejected for `cooldown`. The next request is then let through
+/// as a probe: success closes the circuit, failure opens it again.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(Inner {
+                state: CircuitState::Closed,
+                failures: VecDeque::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Check whether a request may be sent.
+    ///
+    /// Rejections carry how long until the next probe, or `None` while a probe
+    /// is already in flight.
+    pub fn acquire(&self) -> Result<(), Option<Duration>> {
+        if self.config.failure_threshold == 0 {
+            return Ok(());
+        }
+
+        let mut inner = self.lock();
+        let now = Instant::now();
+        match inner.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open { until } if now < until => Err(Some(until - now)),
+            // A probe that never reported back (e.g. its caller was cancelled)
+            // must not hold the circuit half-open forever.
+            CircuitState::HalfOpen { since } if now - since < self.config.cooldown => Err(None),
+            CircuitState::Open { .. } | CircuitState::HalfOpen { .. } => {
+                tracing::info!("Vercel API circuit half-open, sending probe request");
+                inner.state = CircuitState::HalfOpen { since: now };
+                Ok(())
+            }
+        }
+    }
+
+    /// Record a request that reached the API without a server error.
+    pub fn record_success(&self) {
+        let mut inner = self.lock();
+        if let CircuitState::HalfOpen { .. } = inner.state {
+            tracing::info!("Vercel API recovered, circuit closed");
+            inner.state = CircuitState::Closed;
+            inner.failures.clear();
+        }
+    }
+
+    /// Record a server error or a failure to reach the API.
+    pub fn record_failure(&self) {
+        if self.config.failure_threshold == 0 {
+            return;
+        }
+
+        let mut inner = self.lock();
+        let now = Instant::now();
+        match inner.state {
+            CircuitState::Closed => {
+                inner.failures.push_back(now);
+                while inner
+                    .failures
+                    .front()
+                    .is_some_and(|t| now - *t > self.config.window)
+                {
+                    inner.failures.pop_front();
+                }
+                if inner.failures.len() >= self.config.failure_threshold as usize {
+                    tracing::warn!(
+                        "{} Vercel API failures within {:?}, opening circuit for {:?}",
+                        inner.failures.len(),
+                        self.config.window,
+                        self.config.cooldown
+                    );
+                    inner.state = CircuitState::Open {
+                        until: now + self.config.cooldown,
+                    };
+                    inner.failures.clear();
+                }
+            }
+            CircuitState::HalfOpen { .. } => {
+                tracing::warn!("Vercel API probe failed, circuit open again");
+                inner.state = CircuitState::Open {
+                    until: now + self.config.cooldown,
+                };
+            }
+            CircuitState::Open { .. } => {}
+        }
+    }
+}
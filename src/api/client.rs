@@ -1,232 +1,2366 @@
 //! Vercel REST API client with connection pooling.
 
 use anyhow::{Context, Result};
-use reqwest::Client;
-use serde::Deserialize;
+use futures_util::stream::{self, Stream, StreamExt};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::field::Empty;
+use tracing::Span;
 
-use crate::models::{Deployment, DeploymentEvent, Project, User};
+use super::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use super::error::{retry_after, VercelApiError};
+use super::sse::SseParser;
+use crate::models::{
+    Alias, DeployHook, Deployment, DeploymentCheck, DeploymentEvent, DeploymentState, DomainInfo,
+    EdgeConfig, EdgeConfigItem, EnvBulkResult, EnvVar, EnvVarInput, FileTree, Function, GitLink,
+    NewProject, PaginatedResponse, Project, ProjectMember, ProjectProtection, ProjectUpdate,
+    ProtectionBypass, ProtectionUpdate, Secret, Team, TeamMember, User, Webhook,
+};
 
 const API_BASE: &str = "https://api.vercel.com";
 
+/// Upper bound on how long a streamed request may stay open.
+const STREAM_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Largest page size accepted by Vercel's list endpoints.
+const PAGE_SIZE_MAX: i32 = 100;
+
+/// Pause between page requests when collecting every page of a listing.
+const PAGE_DELAY: Duration = Duration::from_millis(250);
+
+/// Default window in which identical `get_deployment` calls share one request.
+const DEFAULT_DEDUP_WINDOW: Duration = Duration::from_millis(100);
+
+/// Append a `teamId` query parameter when a team scope is given.
+fn scoped(mut endpoint: String, team_id: Option<&str>) -> String {
+    if let Some(team_id) = team_id {
+        let separator = if endpoint.contains('?') { '&' } else { '?' };
+        endpoint.push_str(&format!("{}teamId={}", separator, team_id));
+    }
+    endpoint
+}
+
+/// Percent-encode a query parameter value.
+fn encode_query(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+/// Extract the hostname when a deployment reference is a URL rather than an ID.
+///
+/// `https://my-app-abc123.vercel.app/some/path` becomes `my-app-abc123.vercel.app`.
+fn deployment_host(reference: &str) -> Option<&str> {
+    let (has_scheme, rest) = match reference.split_once("://") {
+        Some((_, rest)) => (true, rest),
+        None => (false, reference),
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    (has_scheme || host.contains('.')).then_some(host)
+}
+
+/// Filters for listing deployments.
+#[derive(Debug, Clone, Default)]
+pub struct DeploymentListOptions {
+    pub project_id: Option<String>,
+    pub limit: Option<i32>,
+    /// Comma-separated ready states, e.g. `ERROR,CANCELED`.
+    pub state: Option<String>,
+    /// `production` or `preview`.
+    pub target: Option<String>,
+    /// Git branch the deployment was built from.
+    pub branch: Option<String>,
+    /// Only deployments created at or after this time (ms since epoch).
+    pub since: Option<i64>,
+}
+
+/// Items gathered by following pagination cursors.
+#[derive(Debug)]
+pub struct CollectedPages<T> {
+    pub items: Vec<T>,
+    /// Cursor for the next unread page, if collection stopped early.
+    pub next: Option<i64>,
+    /// Collection stopped because `max_items` was reached.
+    pub truncated: bool,
+    /// Collection stopped because a page request failed.
+    pub error: Option<String>,
+}
+
+/// Follow pagination cursors from `fetch`, starting at `start`, until
+/// exhausted, `max_items` is reached, or a page fails. A failed page keeps the
+/// items collected so far.
+async fn collect_pages<T, F, Fut>(
+    max_items: usize,
+    start: Option<i64>,
+    mut fetch: F,
+) -> CollectedPages<T>
+where
+    F: FnMut(i32, Option<i64>) -> Fut,
+    Fut: std::future::Future<Output = Result<PaginatedResponse<T>>>,
+{
+    let mut collected = CollectedPages {
+        items: Vec::new(),
+        next: None,
+        truncated: false,
+        error: None,
+    };
+    let mut cursor = start;
+
+    loop {
+        // Never request more than is left under the cap, so `next` resumes exactly.
+        let remaining = max_items - collected.items.len();
+        let limit = remaining.min(PAGE_SIZE_MAX as usize) as i32;
+        match fetch(limit, cursor).await {
+            Ok(page) => {
+                collected.items.extend(page.items);
+                cursor = page.pagination.and_then(|p| p.next);
+            }
+            Err(e) => {
+                collected.next = cursor;
+                collected.error = Some(format!("{:#}", e));
+                break;
+            }
+        }
+
+        if cursor.is_none() {
+            break;
+        }
+        if collected.items.len() >= max_items {
+            collected.next = cursor;
+            collected.truncated = true;
+            break;
+        }
+        tokio::time::sleep(PAGE_DELAY).await;
+    }
+
+    collected
+}
+
+/// Outcome of [`VercelClient::wait_for_deployment`].
+#[derive(Debug)]
+pub struct DeploymentWait {
+    /// The last deployment state fetched.
+    pub deployment: Deployment,
+    pub elapsed: Duration,
+    /// Each change of ready state, in order.
+    pub transitions: Vec<StateTransition>,
+    /// The deployment was still in progress when the timeout expired.
+    pub timed_out: bool,
+}
+
+/// A ready state observed while waiting, and when it was first seen.
+#[derive(Debug)]
+pub struct StateTransition {
+    pub state: DeploymentState,
+    /// Time since waiting started.
+    pub elapsed: Duration,
+}
+
+/// Turn a non-success response into a [`VercelApiError`].
+async fn error_for_status(response: Response) -> Result<Response> {
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        Err(VercelApiError::from_response(response).await.into())
+    }
+}
+
+/// Retry behaviour for transient API failures (network errors, 429, and 5xx).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first request.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each subsequent retry.
+    pub initial_delay: Duration,
+    /// Randomize each delay between 50% and 150% to avoid synchronized retries.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(500),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether a response status indicates a transient failure.
+    ///
+    /// A non-idempotent request is only retried when the API says it did not
+    /// act on it (429 or 503); other server errors may follow a write that
+    /// went through.
+    fn is_retryable(status: StatusCode, idempotent: bool) -> bool {
+        match status.as_u16() {
+            429 | 503 => true,
+            500 | 502 | 504 => idempotent,
+            _ => false,
+        }
+    }
+
+    /// Backoff delay before retry number `retry` (1-based).
+    fn delay(&self, retry: u32) -> Duration {
+        let base = self.initial_delay * 2u32.saturating_pow(retry - 1);
+        if !self.jitter {
+            return base;
+        }
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        base.mul_f64(0.5 + f64::from(nanos % 1000) / 1000.0)
+    }
+}
+
+/// Rate-limit quota reported by the most recent API response.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RateLimitState {
+    /// Requests allowed in the current window (`X-RateLimit-Limit`).
+    pub limit: Option<u64>,
+    /// Requests left in the current window (`X-RateLimit-Remaining`).
+    pub remaining: Option<u64>,
+    /// Unix timestamp in seconds when the window resets (`X-RateLimit-Reset`).
+    pub reset: Option<i64>,
+}
+
+/// A `get_deployment` request shared by concurrent callers for the same endpoint.
+struct InflightDeployment {
+    started: Instant,
+    result: Arc<tokio::sync::OnceCell<Result<Deployment, Arc<anyhow::Error>>>>,
+}
+
 /// Vercel REST API client with persistent connection.
 pub struct VercelClient {
     client: Client,
-    token: String,
+    /// Swappable so the daemon can pick up a new token without restarting.
+    token: Arc<RwLock<String>>,
+    /// Default team scope, applied when a call does not pass its own `team_id`.
+    team_id: Option<String>,
+    base_url: String,
+    retry_policy: RetryPolicy,
+    rate_limit: Arc<Mutex<RateLimitState>>,
+    /// Identical `get_deployment` calls started within this window share one
+    /// request; zero disables deduplication.
+    dedup_window: Duration,
+    inflight: Arc<Mutex<HashMap<String, InflightDeployment>>>,
+    circuit: Arc<CircuitBreaker>,
+}
+
+/// Fluent configuration for [`VercelClient`].
+///
+/// Not every option is set by the daemon; they exist so the client can be
+/// pointed at a mock server or tuned for other environments.
+pub struct VercelClientBuilder {
+    token: Option<String>,
+    team_id: Option<String>,
+    base_url: String,
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    pool_max_idle: usize,
+    /// Proxy for every request; falls back to `HTTPS_PROXY`, then `ALL_PROXY`.
+    proxy_url: Option<String>,
+    retry_policy: RetryPolicy,
+    circuit_breaker: CircuitBreakerConfig,
+    dedup_window: Duration,
+}
+
+impl Default for VercelClientBuilder {
+    fn default() -> Self {
+        Self {
+            token: None,
+            team_id: None,
+            base_url: API_BASE.to_string(),
+            timeout: Duration::from_secs(30),
+            connect_timeout: None,
+            pool_max_idle: 5,
+            proxy_url: None,
+            retry_policy: RetryPolicy::default(),
+            circuit_breaker: CircuitBreakerConfig::default(),
+            dedup_window: DEFAULT_DEDUP_WINDOW,
+        }
+    }
+}
+
+impl VercelClientBuilder {
+    /// Access token (required).
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Default team scope, applied when a call does not pass its own `team_id`.
+    pub fn team_id(mut self, team_id: Option<String>) -> Self {
+        self.team_id = team_id;
+        self
+    }
+
+    /// API base URL (default `https://api.vercel.com`).
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Overall timeout for a request (default 30 s). Streamed requests use
+    /// their own, longer timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Timeout for establishing a connection.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Idle connections kept open per host (default 5).
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn pool_max_idle(mut self, max: usize) -> Self {
+        self.pool_max_idle = max;
+        self
+    }
+
+    /// Send every request through this proxy.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn proxy(mut self, proxy: reqwest::Url) -> Self {
+        self.proxy_url = Some(proxy.to_string());
+        self
+    }
+
+    /// Send every request through this proxy, e.g. `http://proxy.corp:3128`.
+    pub fn proxy_url(mut self, proxy_url: Option<String>) -> Self {
+        self.proxy_url = proxy_url;
+        self
+    }
+
+    /// How transient failures are retried (default: 3 attempts, backing off
+    /// from 500 ms with jitter).
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// When repeated server errors stop requests from being sent
+    /// (default: 5 failures within 30 s, probing again after 30 s).
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = config;
+        self
+    }
+
+    /// Window in which identical `get_deployment` calls are deduplicated
+    /// (default 100 ms). `Duration::ZERO` disables it.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn dedup_window(mut self, window: Duration) -> Self {
+        self.dedup_window = window;
+        self
+    }
+
+    /// Build the client. Fails without a token or on an invalid proxy URL.
+    pub fn build(self) -> Result<VercelClient> {
+        let token = self.token.context("VercelClientBuilder requires a token")?;
+
+        let mut builder = Client::builder()
+            .pool_max_idle_per_host(self.pool_max_idle)
+            .timeout(self.timeout);
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        // An explicit proxy takes every request; one from the environment
+        // honours `NO_PROXY` like other tools reading those variables.
+        let proxy = match self.proxy_url {
+            Some(proxy_url) => Some((proxy_url, None)),
+            None => ["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"]
+                .iter()
+                .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+                .map(|proxy_url| (proxy_url, reqwest::NoProxy::from_env())),
+        };
+        if let Some((proxy_url, no_proxy)) = proxy {
+            let proxy = reqwest::Proxy::all(&proxy_url)
+                // The URL may carry credentials, so it is not echoed back.
+                .context("Invalid proxy URL (check HTTPS_PROXY / ALL_PROXY)")?
+                .no_proxy(no_proxy);
+            builder = builder.proxy(proxy);
+        }
+        let client = builder.build().context("Failed to build HTTP client")?;
+
+        Ok(VercelClient {
+            client,
+            token: Arc::new(RwLock::new(token)),
+            team_id: self.team_id,
+            base_url: self.base_url,
+            retry_policy: self.retry_policy,
+            rate_limit: Arc::new(Mutex::new(RateLimitState::default())),
+            dedup_window: self.dedup_window,
+            inflight: Arc::new(Mutex::new(HashMap::new())),
+            circuit: Arc::new(CircuitBreaker::new(self.circuit_breaker)),
+        })
+    }
 }
 
 impl VercelClient {
-    /// Create a new Vercel client with access token.
-    pub fn new(token: String) -> Result<Self> {
-        let client = Client::builder()
-            .pool_max_idle_per_host(5)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .context("Failed to build HTTP client")?;
+    /// Create a client with the default options.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn new(token: impl Into<String>) -> Result<Self> {
+        Self::builder().token(token).build()
+    }
+
+    /// Start configuring a client; see [`VercelClientBuilder`] for defaults.
+    pub fn builder() -> VercelClientBuilder {
+        VercelClientBuilder::default()
+    }
+
+    /// Team that requests are scoped to when none is given.
+    pub fn default_team_id(&self) -> Option<&str> {
+        self.team_id.as_deref()
+    }
+
+    /// Replace the access token used for subsequent requests.
+    ///
+    /// Returns whether the token actually changed.
+    pub fn set_token(&self, token: String) -> bool {
+        let mut current = self.token.write().unwrap_or_else(|e| e.into_inner());
+        let changed = *current != token;
+        *current = token;
+        changed
+    }
+
+    /// `Authorization` header value for the current token.
+    fn bearer(&self) -> String {
+        let token = self.token.read().unwrap_or_else(|e| e.into_inner());
+        format!("Bearer {}", token)
+    }
+
+    /// Latest rate-limit quota seen from the API.
+    pub fn rate_limit_state(&self) -> RateLimitState {
+        self.rate_limit
+            .lock()
+            .map(|state| state.clone())
+            .unwrap_or_default()
+    }
+
+    /// Update the rate-limit state from a response's headers, if present.
+    fn record_rate_limit(&self, response: &Response) {
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim().to_string())
+        };
+
+        let Some(remaining) = header("X-RateLimit-Remaining").and_then(|v| v.parse().ok()) else {
+            return;
+        };
+
+        if let Ok(mut state) = self.rate_limit.lock() {
+            state.remaining = Some(remaining);
+            state.limit = header("X-RateLimit-Limit").and_then(|v| v.parse().ok());
+            state.reset = header("X-RateLimit-Reset").and_then(|v| v.parse().ok());
+        }
+    }
+
+    /// Fail fast if the circuit breaker is open.
+    fn check_circuit(&self) -> Result<()> {
+        self.circuit
+            .acquire()
+            .map_err(|retry_in| VercelApiError::CircuitOpen { retry_in }.into())
+    }
+
+    /// Feed a send result to the rate-limit tracker and circuit breaker.
+    fn record_outcome(&self, result: &reqwest::Result<Response>) {
+        match result {
+            Ok(response) => {
+                Span::current().record("http.status_code", response.status().as_u16());
+                self.record_rate_limit(response);
+                if response.status().is_server_error() {
+                    self.circuit.record_failure();
+                } else {
+                    self.circuit.record_success();
+                }
+            }
+            Err(e) if e.is_connect() || e.is_timeout() => self.circuit.record_failure(),
+            Err(_) => {}
+        }
+    }
+
+    /// Send a request, retrying transient failures according to the retry policy.
+    ///
+    /// `build` is called once per attempt since a `RequestBuilder` is consumed by `send`.
+    /// Timeouts are only retried for `idempotent` requests, since a POST that
+    /// timed out may still have been applied.
+    async fn send_with_retry<F>(&self, idempotent: bool, build: F) -> Result<Response>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let mut attempt = 1;
+
+        loop {
+            self.check_circuit()?;
+            let result = build().send().await;
+            self.record_outcome(&result);
+            let can_retry = attempt < self.retry_policy.max_attempts;
+
+            let delay = match &result {
+                Ok(response)
+                    if can_retry && RetryPolicy::is_retryable(response.status(), idempotent) =>
+                {
+                    let delay = if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                        retry_after(response)
+                    } else {
+                        None
+                    };
+                    Some(delay.unwrap_or_else(|| self.retry_policy.delay(attempt)))
+                }
+                Err(e) if can_retry && (e.is_connect() || (idempotent && e.is_timeout())) => {
+                    Some(self.retry_policy.delay(attempt))
+                }
+                _ => None,
+            };
+
+            match delay {
+                Some(delay) => {
+                    tracing::warn!(
+                        "Transient Vercel API failure (attempt {}/{}), retrying in {:?}",
+                        attempt,
+                        self.retry_policy.max_attempts,
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return result.context("Failed to send request"),
+            }
+        }
+    }
 
-        Ok(Self { client, token })
+    /// Build the full URL for an endpoint, applying the default team scope
+    /// unless the endpoint already carries its own `teamId`.
+    fn url(&self, endpoint: &str) -> String {
+        let endpoint = if endpoint.contains("teamId=") {
+            endpoint.to_string()
+        } else {
+            scoped(endpoint.to_string(), self.team_id.as_deref())
+        };
+        format!("{}{}", self.base_url, endpoint)
     }
 
     /// Make an authenticated GET request.
-    async fn get<T: for<'de> Deserialize<'de>>(&self, endpoint: &str) -> Result<T> {
-        let url = format!("{}{}", API_BASE, endpoint);
+    #[tracing::instrument(
+        name = "vercel.http",
+        skip_all,
+        fields(http.method = "GET", http.url = Empty, http.status_code = Empty)
+    )]
+    async fn get<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        let url = self.url(endpoint);
+        Span::current().record("http.url", url.as_str());
 
         let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request")?;
+            .send_with_retry(true, || {
+                self.client
+                    .get(&url)
+                    .header("Authorization", self.bearer())
+                    .header("Accept", "application/json")
+            })
+            .await?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            anyhow::bail!("API request failed: {} - {}", status, text);
-        }
+        let response = error_for_status(response).await?;
 
         response.json().await.context("Failed to parse response")
     }
 
     /// Make an authenticated POST request.
-    async fn post<T: for<'de> Deserialize<'de>>(&self, endpoint: &str, body: &Value) -> Result<T> {
-        let url = format!("{}{}", API_BASE, endpoint);
+    #[tracing::instrument(
+        name = "vercel.http",
+        skip_all,
+        fields(http.method = "POST", http.url = Empty, http.status_code = Empty)
+    )]
+    async fn post<B, T>(&self, endpoint: &str, body: &B) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let url = self.url(endpoint);
+        Span::current().record("http.url", url.as_str());
 
         let response = self
+            .send_with_retry(false, || {
+                self.client
+                    .post(&url)
+                    .header("Authorization", self.bearer())
+                    .header("Accept", "application/json")
+                    .header("Content-Type", "application/json")
+                    .json(body)
+            })
+            .await?;
+
+        let response = error_for_status(response).await?;
+
+        response.json().await.context("Failed to parse response")
+    }
+
+    /// Make an authenticated POST request whose response body is ignored.
+    #[tracing::instrument(
+        name = "vercel.http",
+        skip_all,
+        fields(http.method = "POST", http.url = Empty, http.status_code = Empty)
+    )]
+    async fn post_empty<B: Serialize + ?Sized>(&self, endpoint: &str, body: &B) -> Result<()> {
+        let url = self.url(endpoint);
+        Span::current().record("http.url", url.as_str());
+
+        let response = self
+            .send_with_retry(false, || {
+                self.client
+                    .post(&url)
+                    .header("Authorization", self.bearer())
+                    .header("Content-Type", "application/json")
+                    .json(body)
+            })
+            .await?;
+
+        error_for_status(response).await?;
+
+        Ok(())
+    }
+
+    /// Make an authenticated PATCH request.
+    #[tracing::instrument(
+        name = "vercel.http",
+        skip_all,
+        fields(http.method = "PATCH", http.url = Empty, http.status_code = Empty)
+    )]
+    async fn patch<B, T>(&self, endpoint: &str, body: &B) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let url = self.url(endpoint);
+        Span::current().record("http.url", url.as_str());
+
+        self.check_circuit()?;
+        let result = self
             .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.token))
+            .patch(&url)
+            .header("Authorization", self.bearer())
             .header("Accept", "application/json")
             .header("Content-Type", "application/json")
             .json(body)
             .send()
-            .await
-            .context("Failed to send request")?;
+            .await;
+        self.record_outcome(&result);
+        let response = result.context("Failed to send request")?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            anyhow::bail!("API request failed: {} - {}", status, text);
-        }
+        let response = error_for_status(response).await?;
+
+        response.json().await.context("Failed to parse response")
+    }
+
+    /// Make an authenticated DELETE request.
+    ///
+    /// DELETE is idempotent, so transient failures are retried like GETs.
+    async fn delete<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        let response = self.send_delete(endpoint).await?;
 
         response.json().await.context("Failed to parse response")
     }
 
+    /// Make an authenticated DELETE request that returns no body (204).
+    async fn delete_empty(&self, endpoint: &str) -> Result<()> {
+        self.send_delete(endpoint).await?;
+
+        Ok(())
+    }
+
+    /// Send a DELETE request and fail on a non-success status.
+    #[tracing::instrument(
+        name = "vercel.http",
+        skip_all,
+        fields(http.method = "DELETE", http.url = Empty, http.status_code = Empty)
+    )]
+    async fn send_delete(&self, endpoint: &str) -> Result<Response> {
+        let url = self.url(endpoint);
+        Span::current().record("http.url", url.as_str());
+
+        let response = self
+            .send_with_retry(true, || {
+                self.client
+                    .delete(&url)
+                    .header("Authorization", self.bearer())
+                    .header("Accept", "application/json")
+            })
+            .await?;
+
+        error_for_status(response).await
+    }
+
     /// Check if the client can connect to Vercel API.
+    #[tracing::instrument(
+        name = "vercel.http",
+        skip_all,
+        fields(http.method = "GET", http.url = Empty, http.status_code = Empty)
+    )]
     pub async fn ping(&self) -> Result<bool> {
-        let url = format!("{}/v2/user", API_BASE);
+        let url = format!("{}/v2/user", self.base_url);
+        Span::current().record("http.url", url.as_str());
 
         let response = self
             .client
             .get(&url)
-            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Authorization", self.bearer())
             .header("Accept", "application/json")
             .send()
             .await
             .context("Failed to ping Vercel API")?;
+        Span::current().record("http.status_code", response.status().as_u16());
+        self.record_rate_limit(&response);
 
         Ok(response.status().is_success())
     }
 
-    /// List all projects.
-    pub async fn list_projects(&self, limit: Option<i32>) -> Result<Vec<Project>> {
+    /// List a single page of projects.
+    ///
+    /// `until` is the `pagination.next` cursor from a previous page.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_projects_page(
+        &self,
+        limit: Option<i32>,
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> Result<PaginatedResponse<Project>> {
         let limit = limit.unwrap_or(20);
-        let endpoint = format!("/v9/projects?limit={}", limit);
+        let mut endpoint = format!("/v9/projects?limit={}", limit);
 
-        #[derive(Deserialize)]
-        struct ProjectsResponse {
-            projects: Vec<Project>,
+        if let Some(until) = until {
+            endpoint.push_str(&format!("&until={}", until));
         }
+        let endpoint = scoped(endpoint, team_id);
 
-        let response: ProjectsResponse = self.get(&endpoint).await?;
-        Ok(response.projects)
+        self.get(&endpoint).await
     }
 
-    /// Get a specific project by ID or name.
-    pub async fn get_project(&self, project_id: &str) -> Result<Project> {
-        let endpoint = format!("/v9/projects/{}", project_id);
-        self.get(&endpoint).await
+    /// List every project, following pagination cursors until exhausted or
+    /// `max_items` have been collected.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_projects_all(
+        &self,
+        max_items: usize,
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> CollectedPages<Project> {
+        collect_pages(max_items, until, |limit, cursor| {
+            self.list_projects_page(Some(limit), cursor, team_id)
+        })
+        .await
     }
 
-    /// List deployments (optionally filtered by project).
-    pub async fn list_deployments(
+    /// List a single page of a project's members.
+    ///
+    /// `until` is the `pagination.next` cursor from a previous page.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_project_members_page(
         &self,
-        project_id: Option<&str>,
+        project_id: &str,
         limit: Option<i32>,
-    ) -> Result<Vec<Deployment>> {
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> Result<PaginatedResponse<ProjectMember>> {
         let limit = limit.unwrap_or(20);
-        let mut endpoint = format!("/v6/deployments?limit={}", limit);
+        let mut endpoint = format!("/v1/projects/{}/members?limit={}", project_id, limit);
 
-        if let Some(pid) = project_id {
-            endpoint.push_str(&format!("&projectId={}", pid));
+        if let Some(until) = until {
+            endpoint.push_str(&format!("&until={}", until));
         }
+        let endpoint = scoped(endpoint, team_id);
 
-        #[derive(Deserialize)]
-        struct DeploymentsResponse {
-            deployments: Vec<Deployment>,
-        }
+        self.get(&endpoint).await
+    }
 
-        let response: DeploymentsResponse = self.get(&endpoint).await?;
-        Ok(response.deployments)
+    /// List every member of a project, following pagination cursors until
+    /// exhausted or `max_items` have been collected.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_project_members_all(
+        &self,
+        project_id: &str,
+        max_items: usize,
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> CollectedPages<ProjectMember> {
+        collect_pages(max_items, until, |limit, cursor| {
+            self.list_project_members_page(project_id, Some(limit), cursor, team_id)
+        })
+        .await
     }
 
-    /// Get a specific deployment by ID or URL.
-    pub async fn get_deployment(&self, deployment_id: &str) -> Result<Deployment> {
-        let endpoint = format!("/v13/deployments/{}", deployment_id);
-        self.get(&endpoint).await
+    /// Add a team member to a project with a project role.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_project_member(
+        &self,
+        project_id: &str,
+        uid: &str,
+        role: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let endpoint = scoped(format!("/v1/projects/{}/members", project_id), team_id);
+        let body = serde_json::json!({ "uid": uid, "role": role });
+        let _: Value = self.post(&endpoint, &body).await?;
+        Ok(())
     }
 
-    /// Get deployment events/logs.
-    pub async fn get_deployment_events(&self, deployment_id: &str) -> Result<Vec<DeploymentEvent>> {
-        let endpoint = format!("/v2/deployments/{}/events", deployment_id);
-        self.get(&endpoint).await
+    /// Remove a member from a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn remove_project_member(
+        &self,
+        project_id: &str,
+        uid: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let endpoint = scoped(
+            format!("/v1/projects/{}/members/{}", project_id, uid),
+            team_id,
+        );
+        let _: Value = self.delete(&endpoint).await?;
+        Ok(())
     }
 
-    /// Get current user info.
-    #[allow(dead_code)]
-    pub async fn get_user(&self) -> Result<User> {
-        #[derive(Deserialize)]
-        struct UserResponse {
-            user: User,
+    /// List a single page of a team's members.
+    ///
+    /// `until` is the `pagination.next` cursor from a previous page.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_team_members_page(
+        &self,
+        team_id: &str,
+        limit: Option<i32>,
+        until: Option<i64>,
+    ) -> Result<PaginatedResponse<TeamMember>> {
+        let limit = limit.unwrap_or(20);
+        let mut endpoint = format!("/v2/teams/{}/members?limit={}", team_id, limit);
+
+        if let Some(until) = until {
+            endpoint.push_str(&format!("&until={}", until));
         }
+        let endpoint = scoped(endpoint, Some(team_id));
 
-        let response: UserResponse = self.get("/v2/user").await?;
-        Ok(response.user)
+        self.get(&endpoint).await
     }
 
-    /// Get user info as raw JSON.
-    pub async fn get_user_raw(&self) -> Result<Value> {
-        self.get("/v2/user").await
+    /// List every member of a team, following pagination cursors until
+    /// exhausted or `max_items` have been collected.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_team_members_all(
+        &self,
+        team_id: &str,
+        max_items: usize,
+        until: Option<i64>,
+    ) -> CollectedPages<TeamMember> {
+        collect_pages(max_items, until, |limit, cursor| {
+            self.list_team_members_page(team_id, Some(limit), cursor)
+        })
+        .await
     }
 
-    /// List environment variables for a project.
-    pub async fn list_env_vars(&self, project_id: &str, target: Option<&str>) -> Result<Value> {
-        let mut endpoint = format!("/v9/projects/{}/env", project_id);
-        if let Some(t) = target {
-            endpoint.push_str(&format!("?target={}", t));
-        }
+    /// Invite a user to a team by email.
+    #[tracing::instrument(skip_all)]
+    pub async fn invite_team_member(
+        &self,
+        team_id: &str,
+        email: &str,
+        role: &str,
+    ) -> Result<Value> {
+        let endpoint = scoped(format!("/v1/teams/{}/members", team_id), Some(team_id));
+        let body = serde_json::json!({ "email": email, "role": role });
+        self.post(&endpoint, &body).await
+    }
 
-        #[derive(Deserialize)]
-        struct EnvVarsResponse {
-            envs: Vec<Value>,
+    /// Change a team member's role.
+    #[tracing::instrument(skip_all)]
+    pub async fn update_team_member_role(
+        &self,
+        team_id: &str,
+        uid: &str,
+        role: &str,
+    ) -> Result<Value> {
+        let endpoint = scoped(
+            format!("/v1/teams/{}/members/{}", team_id, uid),
+            Some(team_id),
+        );
+        let body = serde_json::json!({ "role": role });
+        self.patch(&endpoint, &body).await
+    }
+
+    /// Remove a member from a team.
+    #[tracing::instrument(skip_all)]
+    pub async fn remove_team_member(&self, team_id: &str, uid: &str) -> Result<Value> {
+        let endpoint = scoped(
+            format!("/v1/teams/{}/members/{}", team_id, uid),
+            Some(team_id),
+        );
+        self.delete(&endpoint).await
+    }
+
+    /// Get a specific project by ID or name.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_project(&self, project_id: &str, team_id: Option<&str>) -> Result<Project> {
+        let endpoint = scoped(format!("/v9/projects/{}", project_id), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// Get the Git repository a project is connected to, if any.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_project_git_info(
+        &self,
+        project_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Option<GitLink>> {
+        let project = self.get_project(project_id, team_id).await?;
+        Ok(project.link)
+    }
+
+    /// Purge a project's Data Cache, returning the API's acknowledgment
+    /// (`null` when the response has no body).
+    #[tracing::instrument(skip_all)]
+    pub async fn purge_data_cache(&self, project_id: &str, team_id: Option<&str>) -> Result<Value> {
+        let endpoint = scoped(
+            format!(
+                "/v1/data-cache/purge-all?projectIdOrName={}",
+                encode_query(project_id)
+            ),
+            team_id,
+        );
+        let response = self.send_delete(&endpoint).await?;
+        let text = response.text().await.context("Failed to read response")?;
+        if text.trim().is_empty() {
+            return Ok(Value::Null);
         }
+        serde_json::from_str(&text).context("Failed to parse response")
+    }
 
-        let response: EnvVarsResponse = self.get(&endpoint).await?;
-        Ok(serde_json::json!({
-            "env_vars": response.envs,
-            "count": response.envs.len(),
-        }))
+    /// Create a new project.
+    #[tracing::instrument(skip_all)]
+    pub async fn create_project(
+        &self,
+        project: &NewProject,
+        team_id: Option<&str>,
+    ) -> Result<Project> {
+        let endpoint = scoped("/v10/projects".to_string(), team_id);
+        self.post(&endpoint, project).await
     }
 
-    /// Set an environment variable for a project.
-    pub async fn set_env_var(
+    /// Update project settings.
+    #[tracing::instrument(skip_all)]
+    pub async fn update_project(
         &self,
         project_id: &str,
-        key: &str,
-        value: &str,
-        target: Option<Vec<&str>>,
-        env_type: Option<&str>,
-    ) -> Result<Value> {
-        let endpoint = format!("/v10/projects/{}/env", project_id);
+        update: &ProjectUpdate,
+        team_id: Option<&str>,
+    ) -> Result<Project> {
+        let endpoint = scoped(format!("/v9/projects/{}", project_id), team_id);
+        self.patch(&endpoint, update).await
+    }
 
-        let targets = target.unwrap_or_else(|| vec!["production", "preview", "development"]);
-        let env_type = env_type.unwrap_or("encrypted");
+    /// Pause a project so it stops serving traffic.
+    #[tracing::instrument(skip_all)]
+    pub async fn pause_project(&self, project_id: &str, team_id: Option<&str>) -> Result<()> {
+        let endpoint = scoped(format!("/v1/projects/{}/pause", project_id), team_id);
+        let body = serde_json::json!({});
+        self.post_empty(&endpoint, &body).await
+    }
 
-        let body = serde_json::json!({
-            "key": key,
-            "value": value,
-            "target": targets,
-            "type": env_type
-        });
+    /// Resume serving traffic for a paused project.
+    #[tracing::instrument(skip_all)]
+    pub async fn unpause_project(&self, project_id: &str, team_id: Option<&str>) -> Result<()> {
+        let endpoint = scoped(format!("/v1/projects/{}/unpause", project_id), team_id);
+        let body = serde_json::json!({});
+        self.post_empty(&endpoint, &body).await
+    }
 
-        let result: Value = self.post(&endpoint, &body).await?;
-        Ok(serde_json::json!({
-            "created": true,
-            "env": result
-        }))
+    /// Get a project's deployment protection settings.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_protection(
+        &self,
+        project_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<ProjectProtection> {
+        Ok(self.get_project(project_id, team_id).await?.protection())
     }
 
-    /// List domains for a project.
-    pub async fn list_domains(&self, project_id: &str) -> Result<Value> {
-        let endpoint = format!("/v9/projects/{}/domains", project_id);
+    /// Change a project's deployment protection settings.
+    #[tracing::instrument(skip_all)]
+    pub async fn update_protection(
+        &self,
+        project_id: &str,
+        update: &ProtectionUpdate,
+        team_id: Option<&str>,
+    ) -> Result<ProjectProtection> {
+        let endpoint = scoped(format!("/v9/projects/{}", project_id), team_id);
+        let project: Project = self.patch(&endpoint, update).await?;
+        Ok(project.protection())
+    }
 
-        #[derive(Deserialize)]
-        struct DomainsResponse {
-            domains: Vec<Value>,
-        }
+    /// Get a project's protection bypass secrets, keyed by secret.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_protection_bypass(
+        &self,
+        project_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<HashMap<String, ProtectionBypass>> {
+        let project = self.get_project(project_id, team_id).await?;
+        Ok(project.protection_bypass.unwrap_or_default())
+    }
 
-        let response: DomainsResponse = self.get(&endpoint).await?;
-        Ok(serde_json::json!({
-            "domains": response.domains,
-            "count": response.domains.len(),
-        }))
+    /// Generate an automation bypass secret, revoking `replace` in the same
+    /// request when given. Returns the project's secrets afterwards.
+    #[tracing::instrument(skip_all)]
+    pub async fn generate_protection_bypass(
+        &self,
+        project_id: &str,
+        replace: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<HashMap<String, ProtectionBypass>> {
+        let body = match replace {
+            Some(secret) => {
+                serde_json::json!({ "revoke": { "secret": secret, "regenerate": true } })
+            }
+            None => serde_json::json!({ "generate": {} }),
+        };
+        self.patch_protection_bypass(project_id, &body, team_id)
+            .await
     }
 
-    /// Redeploy a deployment.
-    pub async fn redeploy(&self, deployment_id: &str) -> Result<Value> {
-        let endpoint = format!("/v13/deployments/{}/redeploy", deployment_id);
-        let body = serde_json::json!({});
-        self.post(&endpoint, &body).await
+    /// Revoke a protection bypass secret. Returns the remaining secrets.
+    #[tracing::instrument(skip_all)]
+    pub async fn revoke_protection_bypass(
+        &self,
+        project_id: &str,
+        secret: &str,
+        team_id: Option<&str>,
+    ) -> Result<HashMap<String, ProtectionBypass>> {
+        let body = serde_json::json!({ "revoke": { "secret": secret, "regenerate": false } });
+        self.patch_protection_bypass(project_id, &body, team_id)
+            .await
+    }
+
+    async fn patch_protection_bypass(
+        &self,
+        project_id: &str,
+        body: &Value,
+        team_id: Option<&str>,
+    ) -> Result<HashMap<String, ProtectionBypass>> {
+        let endpoint = scoped(
+            format!("/v1/projects/{}/protection-bypass", project_id),
+            team_id,
+        );
+        let response: Value = self.patch(&endpoint, body).await?;
+        let secrets: Option<HashMap<String, ProtectionBypass>> =
+            serde_json::from_value(response["protectionBypass"].clone())
+                .context("Failed to parse protection bypass secrets")?;
+        Ok(secrets.unwrap_or_default())
+    }
+
+    /// Enable or disable all cron jobs of a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn set_crons_enabled(
+        &self,
+        project_id: &str,
+        enabled: bool,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let endpoint = scoped(format!("/v1/projects/{}/crons", project_id), team_id);
+        let body = serde_json::json!({ "enabled": enabled });
+        let _: Value = self.patch(&endpoint, &body).await?;
+        Ok(())
+    }
+
+    /// Delete a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn delete_project(&self, project_id: &str, team_id: Option<&str>) -> Result<()> {
+        let endpoint = scoped(format!("/v9/projects/{}", project_id), team_id);
+        self.delete_empty(&endpoint).await
+    }
+
+    /// List deployments matching `options`.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_deployments(
+        &self,
+        options: &DeploymentListOptions,
+        team_id: Option<&str>,
+    ) -> Result<Vec<Deployment>> {
+        Ok(self
+            .list_deployments_page(options, None, team_id)
+            .await?
+            .items)
+    }
+
+    /// List a single page of deployments matching `options`.
+    ///
+    /// `until` is the `pagination.next` cursor from a previous page.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_deployments_page(
+        &self,
+        options: &DeploymentListOptions,
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> Result<PaginatedResponse<Deployment>> {
+        let limit = options.limit.unwrap_or(20);
+        let mut endpoint = format!("/v6/deployments?limit={}", limit);
+
+        if let Some(pid) = &options.project_id {
+            endpoint.push_str(&format!("&projectId={}", pid));
+        }
+        if let Some(state) = &options.state {
+            endpoint.push_str(&format!("&state={}", state));
+        }
+        if let Some(target) = &options.target {
+            endpoint.push_str(&format!("&target={}", target));
+        }
+        if let Some(branch) = &options.branch {
+            endpoint.push_str(&format!("&meta-githubCommitRef={}", encode_query(branch)));
+        }
+        if let Some(since) = options.since {
+            endpoint.push_str(&format!("&since={}", since));
+        }
+        if let Some(until) = until {
+            endpoint.push_str(&format!("&until={}", until));
+        }
+        let endpoint = scoped(endpoint, team_id);
+
+        self.get(&endpoint).await
+    }
+
+    /// List every deployment matching `options`, following pagination cursors
+    /// until exhausted or `max_items` have been collected.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_deployments_all(
+        &self,
+        options: &DeploymentListOptions,
+        max_items: usize,
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> CollectedPages<Deployment> {
+        collect_pages(max_items, until, |limit, cursor| {
+            let options = DeploymentListOptions {
+                limit: Some(limit),
+                ..options.clone()
+            };
+            async move { self.list_deployments_page(&options, cursor, team_id).await }
+        })
+        .await
+    }
+
+    /// Get a specific deployment by ID or URL.
+    ///
+    /// Anything that looks like a hostname or URL (e.g. a preview URL pasted
+    /// from a PR comment) is looked up by hostname instead of ID.
+    ///
+    /// Concurrent calls for the same deployment within the dedup window wait
+    /// on the first call's request instead of sending their own.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn get_deployment(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Deployment> {
+        let endpoint = match deployment_host(deployment_id) {
+            Some(host) => format!("/v13/deployments/get?url={}", encode_query(host)),
+            None => format!("/v13/deployments/{}", deployment_id),
+        };
+        let endpoint = scoped(endpoint, team_id);
+        if self.dedup_window.is_zero() {
+            return self.get(&endpoint).await;
+        }
+
+        let result = {
+            let mut inflight = self.inflight.lock().unwrap_or_else(|e| e.into_inner());
+            // Finished requests are only reused within the window; pending
+            // ones are joined however long they have been running.
+            inflight.retain(|_, entry| {
+                !entry.result.initialized() || entry.started.elapsed() < self.dedup_window
+            });
+            inflight
+                .entry(endpoint.clone())
+                .or_insert_with(|| InflightDeployment {
+                    started: Instant::now(),
+                    result: Arc::default(),
+                })
+                .result
+                .clone()
+        };
+
+        let shared = result
+            .get_or_init(|| async { self.get(&endpoint).await.map_err(Arc::new) })
+            .await;
+        match shared {
+            Ok(deployment) => Ok(deployment.clone()),
+            // Keep API errors typed so callers can still tell a 404 from a 403.
+            Err(e) => Err(match e.downcast_ref::<VercelApiError>() {
+                Some(api) => api.clone().into(),
+                None => anyhow::anyhow!("{:#}", e),
+            }),
+        }
+    }
+
+    /// Poll a deployment until it reaches a terminal state or `timeout` expires.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn watch_deployment(
+        &self,
+        deployment_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+        team_id: Option<&str>,
+    ) -> Result<Deployment> {
+        let wait = self
+            .wait_for_deployment(deployment_id, poll_interval, timeout, team_id)
+            .await?;
+        if wait.timed_out {
+            anyhow::bail!(
+                "Timed out after {:?} waiting for deployment {} (last state: {})",
+                timeout,
+                deployment_id,
+                wait.deployment.ready_state
+            );
+        }
+        Ok(wait.deployment)
+    }
+
+    /// Poll a deployment until it reaches a terminal state or `timeout` expires,
+    /// recording each ready state seen along the way.
+    ///
+    /// Running out of time is not an error: the last seen deployment is
+    /// returned with `timed_out` set.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn wait_for_deployment(
+        &self,
+        deployment_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+        team_id: Option<&str>,
+    ) -> Result<DeploymentWait> {
+        let started = tokio::time::Instant::now();
+        let deadline = started + timeout;
+        let mut transitions: Vec<StateTransition> = Vec::new();
+
+        loop {
+            let deployment = self.get_deployment(deployment_id, team_id).await?;
+            let now = tokio::time::Instant::now();
+            if transitions.last().map(|t| &t.state) != Some(&deployment.ready_state) {
+                transitions.push(StateTransition {
+                    state: deployment.ready_state.clone(),
+                    elapsed: now - started,
+                });
+            }
+
+            let timed_out = !deployment.is_terminal() && now >= deadline;
+            if deployment.is_terminal() || timed_out {
+                return Ok(DeploymentWait {
+                    deployment,
+                    elapsed: now - started,
+                    transitions,
+                    timed_out,
+                });
+            }
+            tokio::time::sleep(poll_interval.min(deadline - now)).await;
+        }
+    }
+
+    /// Cancel an in-progress deployment.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn cancel_deployment(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Deployment> {
+        let endpoint = scoped(
+            format!("/v12/deployments/{}/cancel", deployment_id),
+            team_id,
+        );
+        let body = serde_json::json!({});
+        self.patch(&endpoint, &body).await
+    }
+
+    /// Delete a deployment.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn delete_deployment(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(format!("/v13/deployments/{}", deployment_id), team_id);
+        self.delete(&endpoint).await
+    }
+
+    /// Promote a deployment to be the project's current production deployment.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn promote_deployment(
+        &self,
+        project_id: &str,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let endpoint = scoped(
+            format!("/v10/projects/{}/promote/{}", project_id, deployment_id),
+            team_id,
+        );
+        let body = serde_json::json!({});
+        self.post_empty(&endpoint, &body).await
+    }
+
+    /// Get deployment events/logs.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn get_deployment_events(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Vec<DeploymentEvent>> {
+        let endpoint = scoped(format!("/v2/deployments/{}/events", deployment_id), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// Stream deployment events as they happen (`follow=1`).
+    ///
+    /// The stream ends when Vercel closes the connection, which it does once
+    /// the build has finished.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            vercel.deployment_id = deployment_id,
+            http.method = "GET",
+            http.url = Empty,
+            http.status_code = Empty
+        )
+    )]
+    pub async fn stream_deployment_events(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<impl Stream<Item = Result<DeploymentEvent>>> {
+        let endpoint = scoped(
+            format!("/v2/deployments/{}/events?follow=1", deployment_id),
+            team_id,
+        );
+        let url = self.url(&endpoint);
+        Span::current().record("http.url", url.as_str());
+
+        self.check_circuit()?;
+        let result = self
+            .client
+            .get(&url)
+            .header("Authorization", self.bearer())
+            .header("Accept", "text/event-stream")
+            .timeout(STREAM_TIMEOUT)
+            .send()
+            .await;
+        self.record_outcome(&result);
+        let response = result.context("Failed to send request")?;
+        let response = error_for_status(response).await?;
+
+        let state = (
+            Box::pin(response.bytes_stream()),
+            SseParser::default(),
+            VecDeque::<String>::new(),
+            false,
+        );
+        Ok(stream::unfold(
+            state,
+            |(mut body, mut parser, mut pending, mut done)| async move {
+                loop {
+                    if let Some(payload) = pending.pop_front() {
+                        let event = serde_json::from_str::<DeploymentEvent>(&payload)
+                            .with_context(|| format!("Failed to parse event: {}", payload));
+                        return Some((event, (body, parser, pending, done)));
+                    }
+                    if done {
+                        return None;
+                    }
+                    match body.next().await {
+                        Some(Ok(chunk)) => pending.extend(parser.feed(&chunk)),
+                        Some(Err(e)) => {
+                            done = true;
+                            let err = anyhow::Error::new(e).context("Event stream interrupted");
+                            return Some((Err(err), (body, parser, pending, done)));
+                        }
+                        None => {
+                            done = true;
+                            pending.extend(parser.finish());
+                        }
+                    }
+                }
+            },
+        ))
+    }
+
+    /// List the serverless functions built for a deployment.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn list_functions(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Vec<Function>> {
+        let endpoint = scoped(format!("/v0/deployments/{}/builds", deployment_id), team_id);
+
+        #[derive(Deserialize)]
+        struct BuildsResponse {
+            #[serde(default)]
+            builds: Vec<Build>,
+        }
+        #[derive(Deserialize)]
+        struct Build {
+            #[serde(default)]
+            output: Vec<BuildOutput>,
+        }
+        #[derive(Deserialize)]
+        struct BuildOutput {
+            path: String,
+            #[serde(default)]
+            lambda: Option<Lambda>,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Lambda {
+            #[serde(default)]
+            function_name: Option<String>,
+            #[serde(default)]
+            deployed_to: Vec<String>,
+            #[serde(default)]
+            runtime: Option<String>,
+            #[serde(default)]
+            memory_size: Option<u32>,
+            #[serde(default)]
+            timeout: Option<u32>,
+        }
+
+        let response: BuildsResponse = self.get(&endpoint).await?;
+        Ok(response
+            .builds
+            .into_iter()
+            .flat_map(|b| b.output)
+            .filter_map(|output| {
+                let lambda = output.lambda?;
+                Some(Function {
+                    name: lambda.function_name.unwrap_or(output.path),
+                    region: lambda.deployed_to.into_iter().next(),
+                    runtime: lambda.runtime,
+                    memory: lambda.memory_size,
+                    max_duration: lambda.timeout,
+                })
+            })
+            .collect())
+    }
+
+    /// List the checks registered for a deployment.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn get_deployment_checks(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Vec<DeploymentCheck>> {
+        let endpoint = scoped(format!("/v1/deployments/{}/checks", deployment_id), team_id);
+
+        #[derive(Deserialize)]
+        struct ChecksResponse {
+            #[serde(default)]
+            checks: Vec<DeploymentCheck>,
+        }
+
+        let response: ChecksResponse = self.get(&endpoint).await?;
+        Ok(response.checks)
+    }
+
+    /// Get a single check of a deployment.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn get_check(
+        &self,
+        deployment_id: &str,
+        check_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<DeploymentCheck> {
+        let endpoint = scoped(
+            format!("/v1/deployments/{}/checks/{}", deployment_id, check_id),
+            team_id,
+        );
+        self.get(&endpoint).await
+    }
+
+    /// Ask the integration that owns a check to run it again.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn rerequest_check(
+        &self,
+        deployment_id: &str,
+        check_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let endpoint = scoped(
+            format!(
+                "/v1/deployments/{}/checks/{}/rerequest",
+                deployment_id, check_id
+            ),
+            team_id,
+        );
+        let body = serde_json::json!({});
+        self.post_empty(&endpoint, &body).await
+    }
+
+    /// Get a deployment's source file tree.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn list_deployment_files(
+        &self,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Vec<FileTree>> {
+        let endpoint = scoped(format!("/v6/deployments/{}/files", deployment_id), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// Get a single deployment file's contents, base64-encoded as returned by the API.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn get_deployment_file(
+        &self,
+        deployment_id: &str,
+        file_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<String> {
+        let endpoint = scoped(
+            format!("/v8/deployments/{}/files/{}", deployment_id, file_id),
+            team_id,
+        );
+
+        #[derive(Deserialize)]
+        struct FileResponse {
+            data: String,
+        }
+
+        let response: FileResponse = self.get(&endpoint).await?;
+        Ok(response.data)
+    }
+
+    /// Get current user info.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_user(&self) -> Result<User> {
+        #[derive(Deserialize)]
+        struct UserResponse {
+            user: User,
+        }
+
+        let response: UserResponse = self.get("/v2/user").await?;
+        Ok(response.user)
+    }
+
+    /// List a single page of teams the user belongs to.
+    ///
+    /// `until` is the `pagination.next` cursor from a previous page.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_teams(
+        &self,
+        limit: Option<i32>,
+        until: Option<i64>,
+    ) -> Result<PaginatedResponse<Team>> {
+        let limit = limit.unwrap_or(20);
+        let mut endpoint = format!("/v2/teams?limit={}", limit);
+
+        if let Some(until) = until {
+            endpoint.push_str(&format!("&until={}", until));
+        }
+
+        self.get(&endpoint).await
+    }
+
+    /// Get a team by ID.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_team(&self, team_id: &str) -> Result<Team> {
+        let endpoint = format!("/v2/teams/{}", team_id);
+        self.get(&endpoint).await
+    }
+
+    /// List environment variables for a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_env_vars(
+        &self,
+        project_id: &str,
+        target: Option<&str>,
+        decrypt: bool,
+        team_id: Option<&str>,
+    ) -> Result<Vec<EnvVar>> {
+        let mut query = Vec::new();
+        if let Some(t) = target {
+            query.push(format!("target={}", t));
+        }
+        if decrypt {
+            query.push("decrypt=true".to_string());
+        }
+        let mut endpoint = format!("/v9/projects/{}/env", project_id);
+        if !query.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&query.join("&"));
+        }
+        let endpoint = scoped(endpoint, team_id);
+
+        #[derive(Deserialize)]
+        struct EnvVarsResponse {
+            envs: Vec<EnvVar>,
+        }
+
+        let response: EnvVarsResponse = self.get(&endpoint).await?;
+        Ok(response.envs)
+    }
+
+    /// Set an environment variable for a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn set_env_var(
+        &self,
+        project_id: &str,
+        var: &EnvVarInput,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(format!("/v10/projects/{}/env", project_id), team_id);
+        let result: Value = self.post(&endpoint, var).await?;
+        Ok(serde_json::json!({
+            "created": true,
+            "env": result
+        }))
+    }
+
+    /// Create several environment variables in one request.
+    ///
+    /// With `upsert`, existing vars with the same key and target are updated
+    /// instead of failing. A partial failure is reported in the result, not as an error.
+    #[tracing::instrument(skip_all)]
+    pub async fn bulk_set_env_vars(
+        &self,
+        project_id: &str,
+        vars: &[EnvVarInput],
+        upsert: bool,
+        team_id: Option<&str>,
+    ) -> Result<EnvBulkResult> {
+        let mut endpoint = format!("/v10/projects/{}/env", project_id);
+        if upsert {
+            endpoint.push_str("?upsert=true");
+        }
+        let endpoint = scoped(endpoint, team_id);
+        self.post(&endpoint, vars).await
+    }
+
+    /// Update the value (and optionally targets) of an existing environment variable.
+    #[tracing::instrument(skip_all)]
+    pub async fn update_env_var(
+        &self,
+        project_id: &str,
+        env_id: &str,
+        value: &str,
+        target: Option<&[String]>,
+        team_id: Option<&str>,
+    ) -> Result<EnvVar> {
+        let endpoint = scoped(
+            format!("/v9/projects/{}/env/{}", project_id, env_id),
+            team_id,
+        );
+        let mut body = serde_json::json!({ "value": value });
+        if let Some(target) = target {
+            body["target"] = serde_json::json!(target);
+        }
+        self.patch(&endpoint, &body).await
+    }
+
+    /// Get a single environment variable, decrypted where Vercel allows it.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_env_var(
+        &self,
+        project_id: &str,
+        env_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<EnvVar> {
+        let endpoint = scoped(
+            format!("/v9/projects/{}/env/{}", project_id, env_id),
+            team_id,
+        );
+        self.get(&endpoint).await
+    }
+
+    /// Delete an environment variable from a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn delete_env_var(
+        &self,
+        project_id: &str,
+        env_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(
+            format!("/v9/projects/{}/env/{}", project_id, env_id),
+            team_id,
+        );
+        self.delete(&endpoint).await
+    }
+
+    /// List domains for a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_domains(&self, project_id: &str, team_id: Option<&str>) -> Result<Value> {
+        let endpoint = scoped(format!("/v9/projects/{}/domains", project_id), team_id);
+
+        #[derive(Deserialize)]
+        struct DomainsResponse {
+            domains: Vec<Value>,
+        }
+
+        let response: DomainsResponse = self.get(&endpoint).await?;
+        Ok(serde_json::json!({
+            "domains": response.domains,
+            "count": response.domains.len(),
+        }))
+    }
+
+    /// Add a domain to a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_domain(
+        &self,
+        project_id: &str,
+        domain: &str,
+        redirect: Option<&str>,
+        redirect_status_code: Option<i64>,
+        git_branch: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(format!("/v10/projects/{}/domains", project_id), team_id);
+
+        let mut body = serde_json::json!({ "name": domain });
+        if let Some(redirect) = redirect {
+            body["redirect"] = Value::from(redirect);
+        }
+        if let Some(code) = redirect_status_code {
+            body["redirectStatusCode"] = Value::from(code);
+        }
+        if let Some(branch) = git_branch {
+            body["gitBranch"] = Value::from(branch);
+        }
+
+        self.post(&endpoint, &body).await
+    }
+
+    /// Remove a domain from a project.
+    #[tracing::instrument(skip_all)]
+    pub async fn remove_domain(
+        &self,
+        project_id: &str,
+        domain: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(
+            format!("/v9/projects/{}/domains/{}", project_id, domain),
+            team_id,
+        );
+        self.delete(&endpoint).await
+    }
+
+    /// Get a single project domain, including pending verification challenges.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_project_domain(
+        &self,
+        project_id: &str,
+        domain: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(
+            format!("/v9/projects/{}/domains/{}", project_id, domain),
+            team_id,
+        );
+        self.get(&endpoint).await
+    }
+
+    /// List a single page of the account's domains.
+    ///
+    /// `until` is the `pagination.next` cursor from a previous page.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_account_domains_page(
+        &self,
+        limit: Option<i32>,
+        until: Option<i64>,
+        team_id: Option<&str>,
+    ) -> Result<PaginatedResponse<DomainInfo>> {
+        let limit = limit.unwrap_or(20);
+        let mut endpoint = format!("/v5/domains?limit={}", limit);
+
+        if let Some(until) = until {
+            endpoint.push_str(&format!("&until={}", until));
+        }
+        let endpoint = scoped(endpoint, team_id);
+
+        self.get(&endpoint).await
+    }
+
+    /// List every domain on the account, following pagination cursors until
+    /// exhausted or `max_items` have been collected.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_account_domains_all(
+        &self,
+        max_items: usize,
+        team_id: Option<&str>,
+    ) -> CollectedPages<DomainInfo> {
+        collect_pages(max_items, None, |limit, cursor| {
+            self.list_account_domains_page(Some(limit), cursor, team_id)
+        })
+        .await
+    }
+
+    /// Get registration details of a domain on the account.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_domain(&self, domain: &str, team_id: Option<&str>) -> Result<DomainInfo> {
+        let endpoint = scoped(format!("/v5/domains/{}", domain), team_id);
+
+        #[derive(Deserialize)]
+        struct DomainResponse {
+            domain: DomainInfo,
+        }
+
+        let response: DomainResponse = self.get(&endpoint).await?;
+        Ok(response.domain)
+    }
+
+    /// Get the DNS configuration status of a domain.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_domain_config(&self, domain: &str, team_id: Option<&str>) -> Result<Value> {
+        let endpoint = scoped(format!("/v6/domains/{}/config", domain), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// Trigger verification of a project domain.
+    #[tracing::instrument(skip_all)]
+    pub async fn verify_domain(
+        &self,
+        project_id: &str,
+        domain: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(
+            format!("/v9/projects/{}/domains/{}/verify", project_id, domain),
+            team_id,
+        );
+        let body = serde_json::json!({});
+        self.post(&endpoint, &body).await
+    }
+
+    /// List aliases for a deployment, or for the account (optionally filtered
+    /// by project and domain).
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn list_aliases(
+        &self,
+        project_id: Option<&str>,
+        deployment_id: Option<&str>,
+        domain: Option<&str>,
+        limit: Option<i32>,
+        team_id: Option<&str>,
+    ) -> Result<Vec<Alias>> {
+        let endpoint = match deployment_id {
+            Some(did) => format!("/v2/deployments/{}/aliases", did),
+            None => {
+                let limit = limit.unwrap_or(20);
+                let mut endpoint = format!("/v4/aliases?limit={}", limit);
+                if let Some(pid) = project_id {
+                    endpoint.push_str(&format!("&projectId={}", pid));
+                }
+                if let Some(domain) = domain {
+                    endpoint.push_str(&format!("&domain={}", domain));
+                }
+                endpoint
+            }
+        };
+        let endpoint = scoped(endpoint, team_id);
+
+        #[derive(Deserialize)]
+        struct AliasesResponse {
+            aliases: Vec<Alias>,
+        }
+
+        let response: AliasesResponse = self.get(&endpoint).await?;
+        Ok(response.aliases)
+    }
+
+    /// Point an alias (hostname) at a deployment.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn assign_alias(
+        &self,
+        deployment_id: &str,
+        alias: &str,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let endpoint = scoped(
+            format!("/v2/deployments/{}/aliases", deployment_id),
+            team_id,
+        );
+        let body = serde_json::json!({ "alias": alias });
+        self.post(&endpoint, &body).await
+    }
+
+    /// Delete an alias by ID or hostname.
+    #[tracing::instrument(skip_all)]
+    pub async fn delete_alias(&self, alias_id: &str, team_id: Option<&str>) -> Result<Value> {
+        let endpoint = scoped(format!("/v2/aliases/{}", alias_id), team_id);
+        self.delete(&endpoint).await
+    }
+
+    /// Redeploy a deployment.
+    ///
+    /// `target` overrides the environment of the new deployment, and
+    /// `skip_build_cache` forces a clean build.
+    #[tracing::instrument(skip_all, fields(vercel.deployment_id = deployment_id))]
+    pub async fn redeploy(
+        &self,
+        deployment_id: &str,
+        target: Option<&str>,
+        skip_build_cache: bool,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let mut endpoint = format!("/v13/deployments/{}/redeploy", deployment_id);
+        let mut body = serde_json::json!({});
+        if let Some(target) = target {
+            body["target"] = Value::String(target.to_string());
+        }
+        if skip_build_cache {
+            endpoint.push_str("?forceNew=1");
+            body["withCache"] = Value::Bool(false);
+        }
+        let endpoint = scoped(endpoint, team_id);
+        self.post(&endpoint, &body).await
+    }
+
+    /// List a project's deploy hooks.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_deploy_hooks(
+        &self,
+        project_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Vec<DeployHook>> {
+        let endpoint = scoped(format!("/v6/projects/{}/deploy-hooks", project_id), team_id);
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum DeployHooksResponse {
+            Wrapped {
+                #[serde(rename = "deployHooks")]
+                deploy_hooks: Vec<DeployHook>,
+            },
+            List(Vec<DeployHook>),
+        }
+
+        let response: DeployHooksResponse = self.get(&endpoint).await?;
+        Ok(match response {
+            DeployHooksResponse::Wrapped { deploy_hooks } => deploy_hooks,
+            DeployHooksResponse::List(hooks) => hooks,
+        })
+    }
+
+    /// Create a deploy hook that deploys `git_ref` when its URL is called.
+    #[tracing::instrument(skip_all)]
+    pub async fn create_deploy_hook(
+        &self,
+        project_id: &str,
+        name: &str,
+        git_ref: &str,
+        team_id: Option<&str>,
+    ) -> Result<DeployHook> {
+        let endpoint = scoped(format!("/v6/projects/{}/deploy-hooks", project_id), team_id);
+        let body = serde_json::json!({
+            "name": name,
+            "ref": git_ref,
+        });
+        self.post(&endpoint, &body).await
+    }
+
+    /// Delete a deploy hook.
+    #[tracing::instrument(skip_all)]
+    pub async fn delete_deploy_hook(
+        &self,
+        project_id: &str,
+        hook_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let endpoint = scoped(
+            format!("/v6/projects/{}/deploy-hooks/{}", project_id, hook_id),
+            team_id,
+        );
+        self.delete_empty(&endpoint).await
+    }
+
+    /// List Edge Config stores.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_edge_configs(&self, team_id: Option<&str>) -> Result<Vec<EdgeConfig>> {
+        let endpoint = scoped("/v1/edge-config".to_string(), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// Get an Edge Config store, including its current digest.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_edge_config(
+        &self,
+        config_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<EdgeConfig> {
+        let endpoint = scoped(format!("/v1/edge-config/{}", config_id), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// List the items of an Edge Config.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_edge_config_items(
+        &self,
+        config_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Vec<EdgeConfigItem>> {
+        let endpoint = scoped(format!("/v1/edge-config/{}/items", config_id), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// Apply `{operation, key, value?}` operations (`create`, `update`,
+    /// `upsert`, or `delete`) to an Edge Config's items.
+    #[tracing::instrument(skip_all)]
+    pub async fn patch_edge_config_items(
+        &self,
+        config_id: &str,
+        operations: &[Value],
+        team_id: Option<&str>,
+    ) -> Result<()> {
+        let endpoint = scoped(format!("/v1/edge-config/{}/items", config_id), team_id);
+        let body = serde_json::json!({ "items": operations });
+        let _: Value = self.patch(&endpoint, &body).await?;
+        Ok(())
+    }
+
+    /// List webhooks.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_webhooks(&self, team_id: Option<&str>) -> Result<Vec<Webhook>> {
+        let endpoint = scoped("/v1/webhooks".to_string(), team_id);
+        self.get(&endpoint).await
+    }
+
+    /// Create a webhook. The response carries the signing secret, which the
+    /// API never returns again.
+    #[tracing::instrument(skip_all)]
+    pub async fn create_webhook(
+        &self,
+        url: &str,
+        events: &[String],
+        project_ids: &[String],
+        team_id: Option<&str>,
+    ) -> Result<Webhook> {
+        let endpoint = scoped("/v1/webhooks".to_string(), team_id);
+        let mut body = serde_json::json!({
+            "url": url,
+            "events": events,
+        });
+        if !project_ids.is_empty() {
+            body["projectIds"] = serde_json::json!(project_ids);
+        }
+        self.post(&endpoint, &body).await
+    }
+
+    /// Delete a webhook.
+    #[tracing::instrument(skip_all)]
+    pub async fn delete_webhook(&self, webhook_id: &str, team_id: Option<&str>) -> Result<()> {
+        let endpoint = scoped(format!("/v1/webhooks/{}", webhook_id), team_id);
+        self.delete_empty(&endpoint).await
+    }
+
+    /// List legacy secrets.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_secrets(&self, team_id: Option<&str>) -> Result<Vec<Secret>> {
+        let endpoint = scoped("/v3/secrets".to_string(), team_id);
+
+        #[derive(Deserialize)]
+        struct SecretsResponse {
+            secrets: Vec<Secret>,
+        }
+
+        let response: SecretsResponse = self.get(&endpoint).await?;
+        Ok(response.secrets)
+    }
+
+    /// Create a legacy secret. The value is never returned.
+    #[tracing::instrument(skip_all)]
+    pub async fn create_secret(
+        &self,
+        name: &str,
+        value: &str,
+        team_id: Option<&str>,
+    ) -> Result<Secret> {
+        let endpoint = scoped(format!("/v2/secrets/{}", name), team_id);
+        let body = serde_json::json!({ "name": name, "value": value });
+        self.post(&endpoint, &body).await
+    }
+
+    /// Delete a legacy secret by name or ID.
+    #[tracing::instrument(skip_all)]
+    pub async fn delete_secret(&self, name: &str, team_id: Option<&str>) -> Result<Secret> {
+        let endpoint = scoped(format!("/v2/secrets/{}", name), team_id);
+        self.delete(&endpoint).await
+    }
+
+    /// Fire a deploy hook by its URL.
+    ///
+    /// Hook URLs carry their own secret, so the API token is deliberately not
+    /// sent and the URL is left off the span.
+    #[tracing::instrument(skip_all, fields(http.method = "POST", http.status_code = Empty))]
+    pub async fn trigger_deploy_hook(&self, hook_url: &str) -> Result<Value> {
+        let response = self
+            .client
+            .post(hook_url)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .context("Failed to send request")?;
+        Span::current().record("http.status_code", response.status().as_u16());
+
+        let response = error_for_status(response).await?;
+        response.json().await.context("Failed to parse response")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::{BodyExt, Full};
+    use hyper::body::{Bytes, Incoming};
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper_util::rt::TokioIo;
+    use std::convert::Infallible;
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Serve HTTP/1 on an ephemeral local port, answering with `handle`.
+    async fn serve<F, Fut>(handle: F) -> std::net::SocketAddr
+    where
+        F: Fn(hyper::Request<Incoming>) -> Fut + Clone + Send + 'static,
+        Fut: Future<Output = hyper::Response<Full<Bytes>>> + Send + 'static,
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let handle = handle.clone();
+                tokio::spawn(async move {
+                    let service = service_fn(move |request| {
+                        let response = handle(request);
+                        async move { Ok::<_, Infallible>(response.await) }
+                    });
+                    let _ = http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        });
+        addr
+    }
+
+    #[test]
+    fn explicit_proxy_carries_requests() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let upstream = serve(|request: hyper::Request<Incoming>| async move {
+                let authorized = request
+                    .headers()
+                    .get("Authorization")
+                    .is_some_and(|v| v == "Bearer test-token");
+                let mut response = hyper::Response::new(Full::new(Bytes::from("{}")));
+                if !authorized {
+                    *response.status_mut() = hyper::StatusCode::UNAUTHORIZED;
+                }
+                response
+            })
+            .await;
+
+            // Forwards absolute-form requests to their target, counting them.
+            let proxied = Arc::new(AtomicUsize::new(0));
+            let counter = proxied.clone();
+            let proxy = serve(move |request: hyper::Request<Incoming>| {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    let (parts, body) = request.into_parts();
+                    let body = body.collect().await.unwrap().to_bytes();
+                    let forwarded = Client::builder()
+                        .no_proxy()
+                        .build()
+                        .unwrap()
+                        .request(parts.method, parts.uri.to_string())
+                        .headers(parts.headers)
+                        .body(body)
+                        .send()
+                        .await
+                        .unwrap();
+                    let status = forwarded.status();
+                    let mut response =
+                        hyper::Response::new(Full::new(forwarded.bytes().await.unwrap()));
+                    *response.status_mut() = status;
+                    response
+                }
+            })
+            .await;
+
+            let proxy_url = format!("http://{}", proxy);
+            let by_string = VercelClient::builder()
+                .proxy_url(Some(proxy_url.clone()))
+                .token("test-token")
+                .base_url(format!("http://{}", upstream))
+                .build()
+                .unwrap();
+            let by_url = VercelClient::builder()
+                .proxy(proxy_url.parse().unwrap())
+                .token("test-token")
+                .base_url(format!("http://{}", upstream))
+                .build()
+                .unwrap();
+
+            assert!(by_string.ping().await.unwrap());
+            assert!(by_url.ping().await.unwrap());
+            assert_eq!(proxied.load(Ordering::SeqCst), 2);
+        });
+    }
+
+    /// Answer every request with `status` and `body`, counting the requests.
+    async fn serve_fixed(
+        status: u16,
+        body: &'static str,
+    ) -> (std::net::SocketAddr, Arc<AtomicUsize>) {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let addr = serve(move |_request: hyper::Request<Incoming>| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                let mut response = hyper::Response::new(Full::new(Bytes::from(body)));
+                *response.status_mut() = hyper::StatusCode::from_u16(status).unwrap();
+                response
+            }
+        })
+        .await;
+        (addr, hits)
+    }
+
+    #[test]
+    fn new_uses_default_options() {
+        let client = VercelClient::new("test-token").unwrap();
+        assert_eq!(client.base_url, API_BASE);
+        assert_eq!(client.default_team_id(), None);
+        assert_eq!(client.retry_policy.max_attempts, 3);
+        assert_eq!(client.dedup_window, DEFAULT_DEDUP_WINDOW);
+    }
+
+    #[test]
+    fn mock_base_url_serves_requests() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let (addr, hits) = serve_fixed(200, r#"{"user": {"id": "user_1"}}"#).await;
+            let client = VercelClient::builder()
+                .token("test-token")
+                .base_url(format!("http://{}/", addr))
+                .timeout(Duration::from_secs(5))
+                .connect_timeout(Duration::from_secs(1))
+                .pool_max_idle(1)
+                .build()
+                .unwrap();
+
+            assert_eq!(client.get_user().await.unwrap().id, "user_1");
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+        });
+    }
+
+    #[test]
+    fn retry_policy_bounds_attempts() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let (addr, hits) = serve_fixed(503, "").await;
+            let client = VercelClient::builder()
+                .token("test-token")
+                .base_url(format!("http://{}", addr))
+                .retry_policy(RetryPolicy {
+                    max_attempts: 2,
+                    initial_delay: Duration::from_millis(1),
+                    jitter: false,
+                })
+                .build()
+                .unwrap();
+
+            assert!(client.get_user().await.is_err());
+            assert_eq!(hits.load(Ordering::SeqCst), 2);
+        });
+    }
+
+    #[test]
+    fn circuit_breaker_stops_requests() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let (addr, hits) = serve_fixed(500, "").await;
+            let client = VercelClient::builder()
+                .token("test-token")
+                .base_url(format!("http://{}", addr))
+                .retry_policy(RetryPolicy {
+                    max_attempts: 1,
+                    ..RetryPolicy::default()
+                })
+                .circuit_breaker(CircuitBreakerConfig {
+                    failure_threshold: 1,
+                    window: Duration::from_secs(60),
+                    cooldown: Duration::from_secs(60),
+                })
+                .build()
+                .unwrap();
+
+            let first = client.get_user().await.unwrap_err();
+            assert!(matches!(
+                first.downcast_ref::<VercelApiError>(),
+                Some(VercelApiError::Response { .. })
+            ));
+            let second = client.get_user().await.unwrap_err();
+            assert!(matches!(
+                second.downcast_ref::<VercelApiError>(),
+                Some(VercelApiError::CircuitOpen { .. })
+            ));
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+        });
+    }
+
+    #[test]
+    fn dedup_window_shares_deployment_lookups() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let body = r#"{"uid": "dpl_1", "name": "app", "url": "app.vercel.app"}"#;
+            let (addr, hits) = serve_fixed(200, body).await;
+            let build = |window| {
+                VercelClient::builder()
+                    .token("test-token")
+                    .base_url(format!("http://{}", addr))
+                    .dedup_window(window)
+                    .build()
+                    .unwrap()
+            };
+
+            let shared = build(Duration::from_secs(10));
+            let (a, b) = futures_util::future::join(
+                shared.get_deployment("dpl_1", None),
+                shared.get_deployment("dpl_1", None),
+            )
+            .await;
+            assert_eq!(a.unwrap().uid, "dpl_1");
+            assert_eq!(b.unwrap().uid, "dpl_1");
+            assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+            let unshared = build(Duration::ZERO);
+            unshared.get_deployment("dpl_1", None).await.unwrap();
+            unshared.get_deployment("dpl_1", None).await.unwrap();
+            assert_eq!(hits.load(Ordering::SeqCst), 3);
+        });
     }
 }
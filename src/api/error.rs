@@ -0,0 +1,148 @@
+//! Structured errors returned by the Vercel REST API.
+
+use reqwest::{Response, StatusCode};
+use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
+
+/// A failed Vercel API call.
+#[derive(Debug, Clone)]
+pub enum VercelApiError {
+    /// A non-success response.
+    ///
+    /// Vercel reports failures as `{"error": {"code": "...", "message": "..."}}`;
+    /// bodies that don't follow that shape keep their raw text as the message.
+    /// `retry_after` carries the `Retry-After` header of a 429.
+    Response {
+        status: StatusCode,
+        code: String,
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    /// The request was not sent because the circuit breaker is open after
+    /// repeated server errors. `retry_in` is the time until the next probe,
+    /// or `None` while a probe is already in flight.
+    CircuitOpen { retry_in: Option<Duration> },
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: ErrorDetail,
+}
+
+#[derive(Deserialize)]
+struct ErrorDetail {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl VercelApiError {
+    /// Build an error from a failed response, consuming its body.
+    pub async fn from_response(response: Response) -> Self {
+        let status = response.status();
+        let wait = match status {
+            StatusCode::TOO_MANY_REQUESTS => retry_after(&response),
+            _ => None,
+        };
+        let text = response.text().await.unwrap_or_default();
+        let mut err = Self::from_body(status, &text);
+        if let Self::Response { retry_after, .. } = &mut err {
+            *retry_after = wait;
+        }
+        err
+    }
+
+    /// Parse a Vercel error body, falling back to the raw text.
+    pub fn from_body(status: StatusCode, text: &str) -> Self {
+        let detail = serde_json::from_str::<ErrorBody>(text)
+            .ok()
+            .map(|b| b.error);
+
+        let code = detail
+            .as_ref()
+            .and_then(|d| d.code.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+        let message = detail
+            .and_then(|d| d.message)
+            .unwrap_or_else(|| match text.trim() {
+                "" => status.canonical_reason().unwrap_or("").to_string(),
+                raw => raw.to_string(),
+            });
+
+        Self::Response {
+            status,
+            code,
+            message,
+            retry_after: None,
+        }
+    }
+
+    /// Whether the requested resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::Response { status, code, .. }
+            if code == "not_found" || *status == StatusCode::NOT_FOUND)
+    }
+
+    /// Whether the token lacks access to the resource or scope.
+    pub fn is_forbidden(&self) -> bool {
+        matches!(self, Self::Response { status, code, .. }
+            if code == "forbidden" || *status == StatusCode::FORBIDDEN)
+    }
+
+    /// How long the API asked us to wait before retrying a rate-limited call.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Response { retry_after, .. } => *retry_after,
+            Self::CircuitOpen { .. } => None,
+        }
+    }
+
+    /// Human-readable reason for the failure.
+    pub fn message(&self) -> String {
+        match self {
+            Self::Response { message, .. } => message.clone(),
+            Self::CircuitOpen { .. } => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for VercelApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Response {
+                status,
+                code,
+                message,
+                ..
+            } => write!(f, "API request failed: {} ({}) - {}", status, code, message),
+            Self::CircuitOpen {
+                retry_in: Some(retry_in),
+            } => write!(
+                f,
+                "Vercel API is failing, not sending request (circuit open, retry in {}s)",
+                retry_in.as_secs().max(1)
+            ),
+            Self::CircuitOpen { retry_in: None } => write!(
+                f,
+                "Vercel API is failing, not sending request (circuit open, recovery probe in flight)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VercelApiError {}
+
+/// Parse a `Retry-After` header given in seconds.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get("Retry-After")?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
@@ -1,5 +1,11 @@
 //! Vercel API client module.
 
+mod cache;
+mod circuit_breaker;
 mod client;
+mod error;
+mod sse;
 
-pub use client::VercelClient;
+pub use cache::CachingVercelClient;
+pub use client::{CollectedPages, DeploymentListOptions, DeploymentWait, VercelClient};
+pub use error::VercelApiError;
@@ -0,0 +1,114 @@
+//! Minimal server-sent events parser for streamed deployment events.
+//!
+//! Vercel's `follow=1` event stream sends `data:` frames, but older endpoints
+//! emit one bare JSON object per line; both are accepted.
+
+/// Incremental parser that turns body chunks into complete event payloads.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    /// Feed a chunk of the response body, returning any payloads it completed.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut payloads = Vec::new();
+        while let Some(pos) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.line(line.trim_end_matches(['\r', '\n']), &mut payloads);
+        }
+        payloads
+    }
+
+    /// Flush whatever is left once the body has ended.
+    pub fn finish(&mut self) -> Vec<String> {
+        let mut payloads = Vec::new();
+        if !self.buffer.is_empty() {
+            let rest = std::mem::take(&mut self.buffer);
+            self.line(String::from_utf8_lossy(&rest).trim_end(), &mut payloads);
+        }
+        self.dispatch(&mut payloads);
+        payloads
+    }
+
+    fn line(&mut self, line: &str, payloads: &mut Vec<String>) {
+        if line.is_empty() {
+            self.dispatch(payloads);
+        } else if let Some(data) = line.strip_prefix("data:") {
+            self.data
+                .push(data.strip_prefix(' ').unwrap_or(data).to_string());
+        } else if line.starts_with('{') {
+            // Newline-delimited JSON rather than SSE framing.
+            self.dispatch(payloads);
+            payloads.push(line.to_string());
+        }
+        // Comments (`:`) and `event:`/`id:`/`retry:` fields carry nothing we use.
+    }
+
+    fn dispatch(&mut self, payloads: &mut Vec<String>) {
+        if !self.data.is_empty() {
+            payloads.push(self.data.join("\n"));
+            self.data.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed `chunks` one at a time, then finish, collecting every payload.
+    fn parse(chunks: &[&str]) -> Vec<String> {
+        let mut parser = SseParser::default();
+        let mut payloads = Vec::new();
+        for chunk in chunks {
+            payloads.extend(parser.feed(chunk.as_bytes()));
+        }
+        payloads.extend(parser.finish());
+        payloads
+    }
+
+    #[test]
+    fn lines_split_across_chunks() {
+        let mut parser = SseParser::default();
+        assert!(parser.feed(b"da").is_empty());
+        assert!(parser.feed(b"ta: {\"type\":").is_empty());
+        assert!(parser.feed(b"\"stdout\"}\r\n").is_empty());
+        assert_eq!(parser.feed(b"\r\nda"), vec![r#"{"type":"stdout"}"#]);
+        assert_eq!(parser.feed(b"ta:next\n\n"), vec!["next"]);
+        assert!(parser.finish().is_empty());
+    }
+
+    #[test]
+    fn multi_line_data_is_joined() {
+        let payloads = parse(&["data: first\ndata:", "second\ndata:  indented\n\n"]);
+        assert_eq!(payloads, vec!["first\nsecond\n indented"]);
+    }
+
+    #[test]
+    fn comments_and_other_fields_are_ignored() {
+        let payloads = parse(&[
+            ": keep-alive\n",
+            "event: log\nid: 7\nretry: 1000\n",
+            "data: payload\n",
+            ":\n\n",
+        ]);
+        assert_eq!(payloads, vec!["payload"]);
+    }
+
+    #[test]
+    fn stream_ending_without_blank_line_is_flushed() {
+        assert_eq!(parse(&["data: a\n\ndata: b\n"]), vec!["a", "b"]);
+        assert_eq!(parse(&["data: a\n\ndata: b"]), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn bare_json_lines_are_payloads() {
+        let payloads = parse(&["{\"id\":1}\n{\"id\"", ":2}\n{\"id\":3}"]);
+        assert_eq!(payloads, vec![r#"{"id":1}"#, r#"{"id":2}"#, r#"{"id":3}"#]);
+    }
+}
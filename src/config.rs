@@ -0,0 +1,161 @@
+//! Daemon configuration loaded from `~/.fgp/services/vercel/config.toml`.
+//!
+//! Every field is optional; command-line flags take precedence.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::logging::LogFormat;
+
+/// Profile used when `--profile` is not given.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Default daemon socket path.
+pub const DEFAULT_SOCKET: &str = "~/.fgp/services/vercel/daemon.sock";
+
+/// Written on first run so the available settings are discoverable.
+const DEFAULT_CONFIG: &str = r#"# fgp-vercel configuration. Command-line flags override these values.
+
+# Daemon socket path.
+# socket = "~/.fgp/services/vercel/daemon.sock"
+
+# Log level (trace, debug, info, warn, error) or a full tracing filter.
+# log_level = "info"
+
+# Append logs to this file instead of stderr.
+# log_file = "~/.fgp/services/vercel/daemon.log"
+
+# Log output format: "text" or "json".
+# log_format = "text"
+
+# Cache project listings (60s) and lookups (30s) in memory.
+# cache = false
+
+# Timeout for a Vercel API request, in seconds.
+# timeout_secs = 30
+
+# Send API requests through this proxy (default: HTTPS_PROXY, then ALL_PROXY).
+# proxy = "http://proxy.example.com:3128"
+
+# Vercel access token, used when neither the selected profile nor
+# VERCEL_ACCESS_TOKEN provides one.
+# token = "xxxxx"
+
+# Named accounts, selected with `--profile <name>` ("default" when omitted).
+# [profiles.default]
+# token = "xxxxx"
+#
+# [profiles.work]
+# token = "xxxxx"
+# team_id = "team_xxxxx"
+"#;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub socket: Option<String>,
+    pub log_level: Option<String>,
+    pub log_file: Option<String>,
+    pub log_format: Option<LogFormat>,
+    pub cache: bool,
+    pub timeout_secs: Option<u64>,
+    pub proxy: Option<String>,
+    pub token: Option<String>,
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// A named account under `[profiles.<name>]`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    pub token: Option<String>,
+    pub team_id: Option<String>,
+}
+
+impl Config {
+    /// Path of the config file.
+    pub fn path() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("Could not determine home directory")?;
+        Ok(home.join(".fgp/services/vercel/config.toml"))
+    }
+
+    /// Load the config file, creating it with commented-out defaults if absent.
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+
+        if !path.exists() {
+            Self::write_default(&path)
+                .with_context(|| format!("Failed to create {}", path.display()))?;
+            return Ok(Self::default());
+        }
+
+        Self::read_from(&path)
+    }
+
+    /// Load the config file if it exists, without creating it.
+    pub fn read() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::read_from(&path)
+    }
+
+    fn read_from(path: &std::path::Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    /// The config file may hold a token, so keep it private to the user.
+    fn write_default(path: &std::path::Path) -> std::io::Result<()> {
+        use std::io::Write;
+        use std::os::unix::fs::OpenOptionsExt;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(path)?;
+        file.write_all(DEFAULT_CONFIG.as_bytes())
+    }
+
+    /// Look up a profile by name. Only the default profile may be missing.
+    pub fn profile(&self, name: &str) -> Result<Option<&Profile>> {
+        match self.profiles.get(name) {
+            Some(profile) => Ok(Some(profile)),
+            None if name == DEFAULT_PROFILE => Ok(None),
+            None => anyhow::bail!(
+                "Profile {:?} not found in {}",
+                name,
+                Self::path()?.display()
+            ),
+        }
+    }
+
+    /// Resolve the socket path: CLI flag, then config file, then the default.
+    pub fn socket_path(&self, cli: Option<String>) -> String {
+        let socket = cli
+            .or_else(|| self.socket.clone())
+            .unwrap_or_else(|| DEFAULT_SOCKET.to_string());
+        shellexpand::tilde(&socket).to_string()
+    }
+}
+
+/// Get the Vercel access token for `profile`, falling back to
+/// `VERCEL_ACCESS_TOKEN` and then the top-level `token` of the config file.
+pub fn get_vercel_token(profile: &str) -> Result<String> {
+    let config = Config::load()?;
+    let profile_token = config.profile(profile)?.and_then(|p| p.token.clone());
+
+    profile_token
+        .or_else(|| std::env::var("VERCEL_ACCESS_TOKEN").ok())
+        .or(config.token)
+        .filter(|t| !t.is_empty())
+        .context("No Vercel access token: set one in the selected profile, VERCEL_ACCESS_TOKEN, or config.toml. Create a token at https://vercel.com/account/tokens")
+}
@@ -0,0 +1,131 @@
+//! Parsing and rendering of dotenv (`.env`) files.
+//!
+//! The parser supports `#` comments, an optional `export ` prefix, unquoted values with
+//! trailing comments, single-quoted literals, and double-quoted values with
+//! `\n`/`\"`/`\\` escapes. Quoted values may span multiple lines.
+
+use anyhow::Result;
+
+/// Parse dotenv content into `(key, value)` pairs in file order.
+pub fn parse(content: &str) -> Result<Vec<(String, String)>> {
+    let mut vars = Vec::new();
+    let mut lines = content.lines().enumerate();
+
+    while let Some((idx, raw)) = lines.next() {
+        let line_no = idx + 1;
+        let line = raw.trim_start();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+
+        let (key, rest) = line
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("line {}: expected KEY=VALUE", line_no))?;
+        let key = key.trim();
+        if !is_valid_key(key) {
+            anyhow::bail!("line {}: invalid variable name {:?}", line_no, key);
+        }
+        let rest = rest.trim_start();
+
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let mut body = rest[1..].to_string();
+                loop {
+                    if let Some(end) = closing_quote(&body, quote) {
+                        let trailing = body[end + 1..].trim();
+                        if !trailing.is_empty() && !trailing.starts_with('#') {
+                            anyhow::bail!("line {}: unexpected text after closing quote", line_no);
+                        }
+                        body.truncate(end);
+                        break;
+                    }
+                    match lines.next() {
+                        Some((_, next)) => {
+                            body.push('\n');
+                            body.push_str(next);
+                        }
+                        None => anyhow::bail!("line {}: unterminated quoted value", line_no),
+                    }
+                }
+                if quote == '"' {
+                    unescape(&body)
+                } else {
+                    body
+                }
+            }
+            _ => match rest.find(" #") {
+                Some(pos) => rest[..pos].trim_end().to_string(),
+                None => rest.trim_end().to_string(),
+            },
+        };
+
+        vars.push((key.to_string(), value));
+    }
+
+    Ok(vars)
+}
+
+/// Render one `KEY=value` line, quoting and escaping the value when needed.
+pub fn render_line(key: &str, value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '#' | '"' | '\'' | '\\' | '=' | '$'));
+    if !needs_quotes {
+        return format!("{}={}", key, value);
+    }
+
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    format!("{}=\"{}\"", key, escaped)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Byte offset of the closing quote, skipping backslash escapes in double quotes.
+fn closing_quote(body: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in body.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && quote == '"' {
+            escaped = true;
+        } else if c == quote {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn unescape(body: &str) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
@@ -0,0 +1,251 @@
+//! Tracing setup for the daemon: log filter, destination, and rotation.
+
+use anyhow::{Context, Result};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::EnvFilter;
+
+/// Default `--log-max-size`: 10 MiB.
+pub const DEFAULT_MAX_SIZE: &str = "10M";
+
+/// Build the log filter from `--log-level`.
+///
+/// A bare level applies to this crate and the daemon SDK; anything else is
+/// taken as a full `tracing` filter directive. Defaults to `info` in the
+/// foreground and `warn` when daemonized.
+pub fn filter(log_level: Option<&str>, foreground: bool) -> Result<EnvFilter> {
+    let default_level = if foreground { "info" } else { "warn" };
+    let spec = match log_level.unwrap_or(default_level) {
+        level @ ("trace" | "debug" | "info" | "warn" | "error") => {
+            format!("fgp_vercel={0},fgp_daemon={0}", level)
+        }
+        filter => filter.to_string(),
+    };
+    EnvFilter::try_new(&spec).with_context(|| format!("Invalid --log-level: {}", spec))
+}
+
+/// Parse a byte size such as `1048576`, `512K`, `10M`, or `1G` (binary units).
+pub fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let (digits, multiplier) = match value.char_indices().last() {
+        Some((i, 'k' | 'K')) => (&value[..i], 1 << 10),
+        Some((i, 'm' | 'M')) => (&value[..i], 1 << 20),
+        Some((i, 'g' | 'G')) => (&value[..i], 1 << 30),
+        _ => (value, 1),
+    };
+    let size: u64 = digits.parse().map_err(|_| {
+        format!(
+            "invalid size {:?} (expected e.g. 10M, 512K, 1048576)",
+            value
+        )
+    })?;
+    match size.checked_mul(multiplier) {
+        Some(0) => Err("size must be greater than zero".to_string()),
+        Some(bytes) => Ok(bytes),
+        None => Err(format!("size {:?} is too large", value)),
+    }
+}
+
+/// Log file opened in append mode that rotates to `<path>.1` once it grows
+/// past `max_size` bytes. Only one rotated file is kept.
+pub struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_size: u64,
+}
+
+impl RotatingFile {
+    pub fn open(path: &Path, max_size: u64) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create log directory")?;
+        }
+        let file = Self::open_append(path)
+            .with_context(|| format!("Failed to open log file {}", path.display()))?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            size,
+            max_size,
+        })
+    }
+
+    fn open_append(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        std::fs::rename(&self.path, rotated)?;
+        self.file = Self::open_append(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_size {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Output format selected with `--log-format`.
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line, for log aggregators.
+    Json,
+}
+
+/// Flushes buffered log lines, and exported spans with the `otel` feature,
+/// when dropped.
+pub struct LogGuard {
+    _writer: Option<WorkerGuard>,
+    #[cfg(feature = "otel")]
+    tracer: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+#[cfg(feature = "otel")]
+impl Drop for LogGuard {
+    fn drop(&mut self) {
+        if let Some(tracer) = self.tracer.take() {
+            if let Err(e) = tracer.shutdown() {
+                eprintln!("Failed to flush OpenTelemetry spans: {}", e);
+            }
+        }
+    }
+}
+
+/// Build an OTLP span exporter when `OTEL_EXPORTER_OTLP_ENDPOINT` (or the
+/// traces-specific variant) is set. The exporter reads the standard
+/// `OTEL_EXPORTER_OTLP_*` variables itself.
+#[cfg(feature = "otel")]
+fn otel_tracer() -> Option<opentelemetry_sdk::trace::SdkTracerProvider> {
+    let configured = [
+        "OTEL_EXPORTER_OTLP_ENDPOINT",
+        "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+    ]
+    .iter()
+    .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()));
+    if !configured {
+        return None;
+    }
+
+    let exporter = match opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()
+    {
+        Ok(exporter) => exporter,
+        Err(e) => {
+            eprintln!(
+                "Failed to create OTLP exporter, spans will not be exported: {}",
+                e
+            );
+            return None;
+        }
+    };
+    let resource = opentelemetry_sdk::Resource::builder()
+        .with_service_name(env!("CARGO_PKG_NAME"))
+        .build();
+    Some(
+        opentelemetry_sdk::trace::SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(resource)
+            .build(),
+    )
+}
+
+/// Layer that hands spans to `tracer`, if one was configured.
+#[cfg(feature = "otel")]
+fn otel_layer<S>(
+    tracer: Option<&opentelemetry_sdk::trace::SdkTracerProvider>,
+) -> Option<impl tracing_subscriber::Layer<S>>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    use opentelemetry::trace::TracerProvider;
+
+    tracer.map(|provider| {
+        tracing_opentelemetry::layer().with_tracer(provider.tracer(env!("CARGO_PKG_NAME")))
+    })
+}
+
+/// Install the global subscriber, writing to `log_file` or stderr.
+///
+/// With the `otel` feature, spans are also exported over OTLP when an
+/// endpoint is configured; they go through the same `--log-level` filter.
+///
+/// Must run after daemonizing: the non-blocking writer and the span exporter
+/// own background threads. Keep the returned guard alive so buffered lines
+/// and spans are flushed on exit.
+pub fn init(filter: EnvFilter, format: LogFormat, log_file: Option<RotatingFile>) -> LogGuard {
+    let (writer, guard, ansi) = match log_file {
+        Some(file) => {
+            let (writer, guard) = tracing_appender::non_blocking(file);
+            (BoxMakeWriter::new(writer), Some(guard), false)
+        }
+        None => (BoxMakeWriter::new(io::stderr), None, true),
+    };
+
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(writer);
+
+    #[cfg(not(feature = "otel"))]
+    match format {
+        LogFormat::Text => builder.with_ansi(ansi).init(),
+        LogFormat::Json => builder
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .init(),
+    }
+
+    #[cfg(feature = "otel")]
+    let tracer = {
+        use tracing_subscriber::layer::SubscriberExt;
+        use tracing_subscriber::util::SubscriberInitExt;
+
+        let tracer = otel_tracer();
+        match format {
+            LogFormat::Text => builder
+                .with_ansi(ansi)
+                .finish()
+                .with(otel_layer(tracer.as_ref()))
+                .init(),
+            LogFormat::Json => builder
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .finish()
+                .with(otel_layer(tracer.as_ref()))
+                .init(),
+        }
+        tracer
+    };
+
+    LogGuard {
+        _writer: guard,
+        #[cfg(feature = "otel")]
+        tracer,
+    }
+}
@@ -7,29 +7,45 @@
 //! fgp-vercel start           # Start daemon in background
 //! fgp-vercel start -f        # Start in foreground
 //! fgp-vercel stop            # Stop daemon
+//! fgp-vercel restart         # Stop, then start again
+//! fgp-vercel reload          # Re-read the access token in place
 //! fgp-vercel status          # Check daemon status
 //! ```
+//!
+//! Defaults for these commands can be set in `~/.fgp/services/vercel/config.toml`.
 
 mod api;
+mod config;
+mod dotenv;
+mod logging;
+mod metrics;
 mod models;
+#[cfg(feature = "prometheus")]
+mod prometheus;
 mod service;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use fgp_daemon::{cleanup_socket, FgpServer};
+use nix::errno::Errno;
+use nix::fcntl::{Flock, FlockArg};
+use nix::sys::signal::{kill, Signal};
+use nix::unistd::Pid;
+use std::fs::{File, OpenOptions};
+use std::io::{Seek, SeekFrom, Write};
 use std::path::Path;
 use std::process::Command;
 
+use crate::api::VercelClient;
+use crate::config::{get_vercel_token, Config, DEFAULT_PROFILE};
 use crate::service::VercelService;
 
-/// Get Vercel access token from environment variable.
-fn get_vercel_token() -> Result<String> {
-    std::env::var("VERCEL_ACCESS_TOKEN")
-        .context("VERCEL_ACCESS_TOKEN environment variable not set. Create a token at https://vercel.com/account/tokens")
+/// Resolve the default team scope from the CLI flag (or profile) or `VERCEL_TEAM_ID`.
+fn get_vercel_team_id(team: Option<String>) -> Option<String> {
+    team.or_else(|| std::env::var("VERCEL_TEAM_ID").ok())
+        .filter(|t| !t.is_empty())
 }
 
-const DEFAULT_SOCKET: &str = "~/.fgp/services/vercel/daemon.sock";
-
 #[derive(Parser)]
 #[command(name = "fgp-vercel")]
 #[command(about = "FGP daemon for Vercel deployment operations")]
@@ -37,33 +53,113 @@ const DEFAULT_SOCKET: &str = "~/.fgp/services/vercel/daemon.sock";
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Config profile to take the access token and team from
+    #[arg(long, global = true, default_value = DEFAULT_PROFILE)]
+    profile: String,
+}
+
+/// Options shared by `start` and `restart`.
+#[derive(Args)]
+struct StartArgs {
+    /// Socket path (default: ~/.fgp/services/vercel/daemon.sock)
+    #[arg(short, long)]
+    socket: Option<String>,
+
+    /// Run in foreground (don't daemonize)
+    #[arg(short, long)]
+    foreground: bool,
+
+    /// Default team ID for API calls (default: the profile's team_id, then
+    /// $VERCEL_TEAM_ID)
+    #[arg(short, long)]
+    team: Option<String>,
+
+    /// Log level (trace, debug, info, warn, error) or a full tracing filter
+    /// such as `fgp_vercel=trace,reqwest=warn` (default: info in foreground,
+    /// warn in background)
+    #[arg(long)]
+    log_level: Option<String>,
+
+    /// Append logs to this file instead of stderr (`-` for stderr)
+    #[arg(long)]
+    log_file: Option<String>,
+
+    /// Rotate the log file once it exceeds this size (e.g. 512K, 10M, 1G)
+    #[arg(long, default_value = logging::DEFAULT_MAX_SIZE, value_parser = logging::parse_size)]
+    log_max_size: u64,
+
+    /// Log output format (default: text)
+    #[arg(long, value_enum)]
+    log_format: Option<logging::LogFormat>,
+
+    /// Cache project listings (60s) and lookups (30s) in memory
+    #[arg(long)]
+    cache: bool,
+
+    /// Timeout for a Vercel API request, in seconds (default: 30)
+    #[arg(long, value_name = "SECS")]
+    timeout: Option<u64>,
+
+    /// Send API requests through this proxy (default: $HTTPS_PROXY, then
+    /// $ALL_PROXY)
+    #[arg(long, value_name = "URL")]
+    proxy: Option<String>,
+
+    /// Serve Prometheus metrics at http://<HOST:PORT>/metrics
+    #[cfg(feature = "prometheus")]
+    #[arg(long, value_name = "HOST:PORT")]
+    metrics_addr: Option<std::net::SocketAddr>,
+}
+
+impl StartArgs {
+    /// Fill options not given on the command line from the config file and
+    /// the selected profile.
+    fn with_config(mut self, config: Config, profile: &str) -> Result<Self> {
+        if let Some(profile) = config.profile(profile)? {
+            self.team = self.team.or_else(|| profile.team_id.clone());
+        }
+        self.log_level = self.log_level.or(config.log_level);
+        self.log_file = self.log_file.or(config.log_file);
+        self.log_format = self.log_format.or(config.log_format);
+        self.cache = self.cache || config.cache;
+        self.timeout = self.timeout.or(config.timeout_secs);
+        self.proxy = self.proxy.or(config.proxy);
+        Ok(self)
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Start the FGP daemon
-    Start {
-        /// Socket path (default: ~/.fgp/services/vercel/daemon.sock)
-        #[arg(short, long, default_value = DEFAULT_SOCKET)]
-        socket: String,
+    Start(StartArgs),
 
-        /// Run in foreground (don't daemonize)
+    /// Stop the running daemon
+    Stop {
+        /// Socket path
         #[arg(short, long)]
-        foreground: bool,
+        socket: Option<String>,
     },
 
-    /// Stop the running daemon
-    Stop {
+    /// Restart the daemon (stop if running, then start)
+    Restart(StartArgs),
+
+    /// Re-read the access token in the running daemon without restarting
+    Reload {
         /// Socket path
-        #[arg(short, long, default_value = DEFAULT_SOCKET)]
-        socket: String,
+        #[arg(short, long)]
+        socket: Option<String>,
     },
 
     /// Check daemon status
     Status {
         /// Socket path
-        #[arg(short, long, default_value = DEFAULT_SOCKET)]
-        socket: String,
+        #[arg(short, long)]
+        socket: Option<String>,
+
+        /// Print status as JSON
+        #[arg(short, long)]
+        json: bool,
     },
 }
 
@@ -71,59 +167,152 @@ fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Start { socket, foreground } => cmd_start(socket, foreground),
-        Commands::Stop { socket } => cmd_stop(socket),
-        Commands::Status { socket } => cmd_status(socket),
+        Commands::Start(args) => {
+            let config = Config::load()?;
+            let socket_path = config.socket_path(args.socket.clone());
+            cmd_start(
+                socket_path,
+                cli.profile.clone(),
+                args.with_config(config, &cli.profile)?,
+            )
+        }
+        Commands::Stop { socket } => cmd_stop(daemon_socket_path(socket)),
+        Commands::Restart(args) => {
+            let config = Config::load()?;
+            let socket_path = config.socket_path(args.socket.clone());
+            cmd_restart(
+                socket_path,
+                cli.profile.clone(),
+                args.with_config(config, &cli.profile)?,
+            )
+        }
+        Commands::Reload { socket } => cmd_reload(daemon_socket_path(socket)),
+        Commands::Status { socket, json } => cmd_status(daemon_socket_path(socket), json),
+    }
+}
+
+/// Socket path for commands that only talk to a running daemon. These
+/// neither create the config file nor fail on a broken one, so a bad edit
+/// can't keep a daemon from being stopped.
+fn daemon_socket_path(socket: Option<String>) -> String {
+    if socket.is_some() {
+        return Config::default().socket_path(socket);
     }
+    let config = Config::read().unwrap_or_else(|e| {
+        eprintln!("Warning: {:#}; using the default socket path", e);
+        Config::default()
+    });
+    config.socket_path(None)
 }
 
-fn cmd_start(socket: String, foreground: bool) -> Result<()> {
-    let socket_path = shellexpand::tilde(&socket).to_string();
+fn cmd_start(socket_path: String, profile: String, args: StartArgs) -> Result<()> {
+    let StartArgs {
+        socket: _,
+        foreground,
+        team,
+        log_level,
+        log_file,
+        log_max_size,
+        log_format,
+        cache,
+        timeout,
+        proxy,
+        #[cfg(feature = "prometheus")]
+        metrics_addr,
+    } = args;
+    if timeout == Some(0) {
+        anyhow::bail!("--timeout must be at least 1 second");
+    }
+    let filter = logging::filter(log_level.as_deref(), foreground)?;
+
+    // Open the log file before daemonizing changes the working directory.
+    let log_file = match log_file.as_deref() {
+        None | Some("-") => None,
+        Some(path) => {
+            let path = std::path::absolute(shellexpand::tilde(path).as_ref())
+                .context("Failed to resolve log file path")?;
+            Some(logging::RotatingFile::open(&path, log_max_size)?)
+        }
+    };
 
     // Create parent directory
     if let Some(parent) = Path::new(&socket_path).parent() {
         std::fs::create_dir_all(parent).context("Failed to create socket directory")?;
     }
 
+    // Hold the PID file lock before touching the socket so two concurrent
+    // starts cannot both pass the stale-socket check.
+    let pid_file = format!("{}.pid", socket_path);
+    let pid_lock = lock_pid_file(&pid_file)?;
+
+    remove_stale_socket(&socket_path)?;
+
+    #[cfg(feature = "prometheus")]
+    let metrics_listener = metrics_addr.map(prometheus::bind).transpose()?;
+
     // Get access token BEFORE fork (env access needs parent process)
-    let token = get_vercel_token()?;
+    let token = get_vercel_token(&profile)?;
+    let team_id = get_vercel_team_id(team);
 
-    let pid_file = format!("{}.pid", socket_path);
+    // Build the client before forking too, so a bad proxy URL is reported to
+    // the user. It starts no threads until the runtime drives it.
+    let client = build_client(token, team_id.clone(), timeout, proxy)?;
 
     println!("Starting fgp-vercel daemon...");
     println!("Socket: {}", socket_path);
+    if profile != DEFAULT_PROFILE {
+        println!("Profile: {}", profile);
+    }
+    if let Some(team_id) = &team_id {
+        println!("Team: {}", team_id);
+    }
+    #[cfg(feature = "prometheus")]
+    if let Some(addr) = metrics_addr {
+        println!("Metrics: http://{}/metrics", addr);
+    }
 
     if foreground {
         // Foreground mode - initialize logging and run directly
-        tracing_subscriber::fmt()
-            .with_env_filter("fgp_vercel=debug,fgp_daemon=debug")
-            .init();
+        let _log_guard = logging::init(filter, log_format.unwrap_or_default(), log_file);
+        write_pid(&pid_lock)?;
+        #[cfg(feature = "prometheus")]
+        if let Some(listener) = metrics_listener {
+            prometheus::serve(listener)?;
+        }
 
-        let service = VercelService::new(token).context("Failed to create VercelService")?;
+        let service =
+            VercelService::new(client, profile, cache).context("Failed to create VercelService")?;
         let server =
             FgpServer::new(service, &socket_path).context("Failed to create FGP server")?;
-        server.serve().context("Server error")?;
+        let served = server.serve().context("Server error");
+        release_pid_file(pid_lock, &pid_file);
+        served?;
     } else {
         // Background mode - daemonize first, THEN create service
         // Tokio runtime must be created AFTER fork
         use daemonize::Daemonize;
 
-        let daemonize = Daemonize::new()
-            .pid_file(&pid_file)
-            .working_directory("/tmp");
+        // The PID is written through the lock we already hold; daemonize's own
+        // `pid_file` would try to lock the file again and fail.
+        let daemonize = Daemonize::new().working_directory("/tmp");
 
         match daemonize.start() {
             Ok(_) => {
                 // Child process: initialize logging and run server
-                tracing_subscriber::fmt()
-                    .with_env_filter("fgp_vercel=debug,fgp_daemon=debug")
-                    .init();
+                let _log_guard = logging::init(filter, log_format.unwrap_or_default(), log_file);
+                write_pid(&pid_lock)?;
+                #[cfg(feature = "prometheus")]
+                if let Some(listener) = metrics_listener {
+                    prometheus::serve(listener)?;
+                }
 
-                let service =
-                    VercelService::new(token).context("Failed to create VercelService")?;
+                let service = VercelService::new(client, profile, cache)
+                    .context("Failed to create VercelService")?;
                 let server =
                     FgpServer::new(service, &socket_path).context("Failed to create FGP server")?;
-                server.serve().context("Server error")?;
+                let served = server.serve().context("Server error");
+                release_pid_file(pid_lock, &pid_file);
+                served?;
             }
             Err(e) => {
                 eprintln!("Failed to daemonize: {}", e);
@@ -135,8 +324,89 @@ fn cmd_start(socket: String, foreground: bool) -> Result<()> {
     Ok(())
 }
 
-fn cmd_stop(socket: String) -> Result<()> {
-    let socket_path = shellexpand::tilde(&socket).to_string();
+/// Build the API client from the daemon's options.
+fn build_client(
+    token: String,
+    team_id: Option<String>,
+    timeout: Option<u64>,
+    proxy: Option<String>,
+) -> Result<VercelClient> {
+    let mut builder = VercelClient::builder()
+        .token(token)
+        .team_id(team_id)
+        .proxy_url(proxy);
+    if let Some(secs) = timeout {
+        builder = builder.timeout(std::time::Duration::from_secs(secs));
+    }
+    builder.build()
+}
+
+/// Take an exclusive lock on the PID file, failing if another daemon holds it.
+///
+/// The lock lives as long as the open file, so it passes to the daemonized
+/// child across the fork and is released when that process exits.
+fn lock_pid_file(pid_file: &str) -> Result<Flock<File>> {
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(pid_file)
+        .with_context(|| format!("Failed to open PID file {}", pid_file))?;
+
+    match Flock::lock(file, FlockArg::LockExclusiveNonblock) {
+        Ok(lock) => Ok(lock),
+        Err((_, Errno::EWOULDBLOCK)) => {
+            let pid = std::fs::read_to_string(pid_file).unwrap_or_default();
+            match pid.trim() {
+                "" => anyhow::bail!("Daemon already running (PID file {} is locked)", pid_file),
+                pid => anyhow::bail!("Daemon already running (PID {})", pid),
+            }
+        }
+        Err((_, e)) => Err(e).with_context(|| format!("Failed to lock PID file {}", pid_file)),
+    }
+}
+
+/// Record the current process ID in the locked PID file.
+fn write_pid(lock: &Flock<File>) -> Result<()> {
+    let mut file: &File = lock;
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    writeln!(file, "{}", std::process::id()).context("Failed to write PID file")
+}
+
+/// Remove the PID file and drop its lock once the server has stopped.
+fn release_pid_file(lock: Flock<File>, pid_file: &str) {
+    let _ = std::fs::remove_file(pid_file);
+    drop(lock);
+}
+
+/// Refuse to start over a live daemon, and delete a socket left behind by one
+/// that died without cleaning up.
+fn remove_stale_socket(socket_path: &str) -> Result<()> {
+    use std::os::unix::net::UnixStream;
+
+    if !Path::new(socket_path).exists() {
+        return Ok(());
+    }
+
+    match UnixStream::connect(socket_path) {
+        Ok(_) => anyhow::bail!(
+            "Daemon is already running at {} (use `restart` to replace it)",
+            socket_path
+        ),
+        Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => {
+            println!("Removing stale socket {}", socket_path);
+            std::fs::remove_file(socket_path).context("Failed to remove stale socket")?;
+            Ok(())
+        }
+        Err(e) => {
+            Err(e).with_context(|| format!("Failed to probe existing socket {}", socket_path))
+        }
+    }
+}
+
+fn cmd_stop(socket_path: String) -> Result<()> {
     let pid_file = format!("{}.pid", socket_path);
 
     if Path::new(&socket_path).exists() {
@@ -161,10 +431,7 @@ fn cmd_stop(socket: String) -> Result<()> {
 
     println!("Stopping fgp-vercel daemon (PID: {})...", pid);
 
-    // Send SIGTERM
-    unsafe {
-        libc::kill(pid, libc::SIGTERM);
-    }
+    send_signal(pid, Signal::SIGTERM)?;
 
     // Wait a moment for cleanup
     std::thread::sleep(std::time::Duration::from_millis(500));
@@ -178,6 +445,169 @@ fn cmd_stop(socket: String) -> Result<()> {
     Ok(())
 }
 
+fn cmd_reload(socket_path: String) -> Result<()> {
+    if !Path::new(&socket_path).exists() {
+        anyhow::bail!(
+            "Daemon is not running (socket {} does not exist)",
+            socket_path
+        );
+    }
+
+    let client = fgp_daemon::FgpClient::new(&socket_path).context("Failed to connect to daemon")?;
+    let response = client
+        .call("vercel.reload", serde_json::json!({}))
+        .context("Failed to send reload request")?;
+
+    if !response.ok {
+        anyhow::bail!(
+            "Reload failed: {}",
+            response
+                .error
+                .unwrap_or_else(|| "unknown error".to_string())
+        );
+    }
+
+    let token_changed = response
+        .result
+        .as_ref()
+        .and_then(|r| r.get("token_changed"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if token_changed {
+        println!("Daemon reloaded (access token updated).");
+    } else {
+        println!("Daemon reloaded (access token unchanged).");
+    }
+
+    Ok(())
+}
+
+/// How long `restart` waits for the old daemon to exit before sending SIGKILL.
+const RESTART_STOP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+fn cmd_restart(socket_path: String, profile: String, args: StartArgs) -> Result<()> {
+    let pid_file = format!("{}.pid", socket_path);
+
+    let pid = std::fs::read_to_string(&pid_file)
+        .ok()
+        .and_then(|s| s.trim().parse::<i32>().ok());
+
+    match pid {
+        Some(pid) if process_alive(pid) => {
+            if !pid_matches_process(pid, "fgp-vercel") {
+                anyhow::bail!("Refusing to stop PID {}: unexpected process", pid);
+            }
+
+            println!("Stopping fgp-vercel daemon (PID: {})...", pid);
+            send_signal(pid, Signal::SIGTERM)?;
+
+            if !wait_for_exit(pid, RESTART_STOP_TIMEOUT) {
+                println!(
+                    "Daemon did not stop within {:?}; sending SIGKILL",
+                    RESTART_STOP_TIMEOUT
+                );
+                send_signal(pid, Signal::SIGKILL)?;
+                if !wait_for_exit(pid, std::time::Duration::from_secs(1)) {
+                    anyhow::bail!(
+                        "Daemon (PID {}) is still running; not starting a new instance",
+                        pid
+                    );
+                }
+            }
+        }
+        // No usable PID file, but a daemon may still be serving the socket.
+        _ if socket_accepting(&socket_path)? => {
+            println!("Stopping fgp-vercel daemon at {}...", socket_path);
+            let client =
+                fgp_daemon::FgpClient::new(&socket_path).context("Failed to connect to daemon")?;
+            let response = client.stop().context("Failed to send stop request")?;
+            if !response.ok {
+                anyhow::bail!(
+                    "Stop failed: {}",
+                    response
+                        .error
+                        .unwrap_or_else(|| "unknown error".to_string())
+                );
+            }
+            if !wait_for_socket_close(&socket_path, RESTART_STOP_TIMEOUT)? {
+                anyhow::bail!(
+                    "Daemon at {} is still running; not starting a new instance",
+                    socket_path
+                );
+            }
+        }
+        _ => println!("Daemon not running."),
+    }
+
+    // A killed daemon leaves its socket and PID file behind. Only clear them
+    // once nothing accepts connections on the socket.
+    if socket_accepting(&socket_path)? {
+        anyhow::bail!(
+            "Daemon at {} is still running; not starting a new instance",
+            socket_path
+        );
+    }
+    let _ = cleanup_socket(&socket_path, Some(Path::new(&pid_file)));
+    let _ = std::fs::remove_file(&pid_file);
+
+    cmd_start(socket_path, profile, args)
+}
+
+/// Whether a daemon accepts connections on the socket. A missing socket or a
+/// refused connection means no daemon; any other failure is an error.
+fn socket_accepting(socket_path: &str) -> Result<bool> {
+    use std::os::unix::net::UnixStream;
+
+    match UnixStream::connect(socket_path) {
+        Ok(_) => Ok(true),
+        Err(e)
+            if matches!(
+                e.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused
+            ) =>
+        {
+            Ok(false)
+        }
+        Err(e) => Err(e).with_context(|| format!("Failed to probe socket {}", socket_path)),
+    }
+}
+
+/// Poll until the socket stops accepting connections, returning false if it
+/// is still accepting after `timeout`.
+fn wait_for_socket_close(socket_path: &str, timeout: std::time::Duration) -> Result<bool> {
+    let deadline = std::time::Instant::now() + timeout;
+    while socket_accepting(socket_path)? {
+        if std::time::Instant::now() >= deadline {
+            return Ok(false);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    Ok(true)
+}
+
+/// Send `signal` to the daemon process.
+fn send_signal(pid: i32, signal: Signal) -> Result<()> {
+    kill(Pid::from_raw(pid), signal)
+        .with_context(|| format!("Failed to send {} to PID {}", signal, pid))
+}
+
+/// Whether a process with this PID exists.
+fn process_alive(pid: i32) -> bool {
+    kill(Pid::from_raw(pid), None).is_ok()
+}
+
+/// Poll until the process exits, returning false if it outlives `timeout`.
+fn wait_for_exit(pid: i32, timeout: std::time::Duration) -> bool {
+    let deadline = std::time::Instant::now() + timeout;
+    while process_alive(pid) {
+        if std::time::Instant::now() >= deadline {
+            return false;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    true
+}
+
 fn pid_matches_process(pid: i32, expected_name: &str) -> bool {
     let output = Command::new("ps")
         .args(["-p", &pid.to_string(), "-o", "comm="])
@@ -192,18 +622,20 @@ fn pid_matches_process(pid: i32, expected_name: &str) -> bool {
     }
 }
 
-fn cmd_status(socket: String) -> Result<()> {
-    let socket_path = shellexpand::tilde(&socket).to_string();
-
+fn cmd_status(socket_path: String, json: bool) -> Result<()> {
     // Check if socket exists
     if !Path::new(&socket_path).exists() {
-        println!("Status: NOT RUNNING");
-        println!("Socket {} does not exist", socket_path);
+        if json {
+            print_status_json("NOT_RUNNING", &socket_path, None, None);
+        } else {
+            println!("Status: NOT RUNNING");
+            println!("Socket {} does not exist", socket_path);
+        }
         return Ok(());
     }
 
     // Try to connect and send health check
-    use std::io::{BufRead, BufReader, Write};
+    use std::io::{BufRead, BufReader};
     use std::os::unix::net::UnixStream;
 
     match UnixStream::connect(&socket_path) {
@@ -218,15 +650,83 @@ fn cmd_status(socket: String) -> Result<()> {
             let mut response = String::new();
             reader.read_line(&mut response)?;
 
-            println!("Status: RUNNING");
-            println!("Socket: {}", socket_path);
-            println!("Health: {}", response.trim());
+            if json {
+                let health = match serde_json::from_str::<serde_json::Value>(response.trim()) {
+                    Ok(mut parsed) => parsed
+                        .get_mut("result")
+                        .map(serde_json::Value::take)
+                        .unwrap_or(parsed),
+                    Err(_) => serde_json::Value::String(response.trim().to_string()),
+                };
+                print_status_json("RUNNING", &socket_path, Some(health), None);
+            } else {
+                println!("Status: RUNNING");
+                println!("Socket: {}", socket_path);
+                println!("Health: {}", response.trim());
+            }
         }
         Err(e) => {
-            println!("Status: NOT RESPONDING");
-            println!("Socket exists but connection failed: {}", e);
+            if json {
+                print_status_json("NOT_RESPONDING", &socket_path, None, Some(e.to_string()));
+            } else {
+                println!("Status: NOT RESPONDING");
+                println!("Socket exists but connection failed: {}", e);
+            }
         }
     }
 
     Ok(())
 }
+
+fn print_status_json(
+    status: &str,
+    socket_path: &str,
+    health: Option<serde_json::Value>,
+    error: Option<String>,
+) {
+    let mut out = serde_json::json!({
+        "status": status,
+        "socket": socket_path,
+        "health": health,
+    });
+    if let Some(error) = error {
+        out["error"] = serde_json::Value::String(error);
+    }
+    println!("{}", out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixListener;
+
+    #[test]
+    fn remove_stale_socket_refuses_live_daemon() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("daemon.sock");
+        let _listener = UnixListener::bind(&socket).unwrap();
+
+        let err = remove_stale_socket(socket.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("already running"), "{}", err);
+        assert!(socket.exists());
+    }
+
+    #[test]
+    fn remove_stale_socket_deletes_dead_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("daemon.sock");
+        drop(UnixListener::bind(&socket).unwrap());
+        assert!(socket.exists());
+
+        remove_stale_socket(socket.to_str().unwrap()).unwrap();
+        assert!(!socket.exists());
+    }
+
+    #[test]
+    fn remove_stale_socket_ignores_missing_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("daemon.sock");
+
+        remove_stale_socket(socket.to_str().unwrap()).unwrap();
+    }
+}
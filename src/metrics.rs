@@ -0,0 +1,101 @@
+//! Rolling per-method call latency, reported by the health checks.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Calls kept per method.
+const WINDOW: usize = 100;
+
+/// Calls older than this drop out of the window.
+const MAX_AGE: Duration = Duration::from_secs(3600);
+
+/// Distinct methods tracked; calls to further names are not recorded.
+const MAX_METHODS: usize = 256;
+
+/// Latency percentiles for one method over its window.
+#[derive(Debug, Clone, Serialize)]
+pub struct LatencyStats {
+    pub count: usize,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Latencies of the last calls to each method, with when they finished.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    calls: Mutex<HashMap<String, VecDeque<(Instant, Duration)>>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a call to `method` that took `duration`.
+    ///
+    /// Returns false, without recording, for a new method once `MAX_METHODS`
+    /// are tracked.
+    pub fn record(&self, method: &str, duration: Duration) -> bool {
+        let mut calls = self.lock();
+        if !calls.contains_key(method) && calls.len() >= MAX_METHODS {
+            return false;
+        }
+
+        let window = calls.entry(method.to_string()).or_default();
+        window.push_back((Instant::now(), duration));
+        if window.len() > WINDOW {
+            window.pop_front();
+        }
+        true
+    }
+
+    /// Percentiles per method, skipping methods with no call in the last hour.
+    pub fn snapshot(&self) -> BTreeMap<String, LatencyStats> {
+        let mut calls = self.lock();
+        let now = Instant::now();
+
+        calls.retain(|_, window| {
+            while window
+                .front()
+                .is_some_and(|(at, _)| now.duration_since(*at) > MAX_AGE)
+            {
+                window.pop_front();
+            }
+            !window.is_empty()
+        });
+
+        calls
+            .iter()
+            .map(|(method, window)| {
+                let mut ms: Vec<f64> = window
+                    .iter()
+                    .map(|(_, d)| d.as_secs_f64() * 1000.0)
+                    .collect();
+                ms.sort_by(f64::total_cmp);
+                let stats = LatencyStats {
+                    count: ms.len(),
+                    p50_ms: percentile(&ms, 0.50),
+                    p95_ms: percentile(&ms, 0.95),
+                    p99_ms: percentile(&ms, 0.99),
+                    max_ms: ms[ms.len() - 1],
+                };
+                (method.clone(), stats)
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, VecDeque<(Instant, Duration)>>> {
+        self.calls.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice, rounded to 0.01 ms.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    let value = sorted[rank.clamp(1, sorted.len()) - 1];
+    (value * 100.0).round() / 100.0
+}
@@ -1,6 +1,8 @@
 //! Data models for Vercel API responses.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Vercel project.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,7 +13,7 @@ pub struct Project {
     #[serde(default)]
     pub account_id: Option<String>,
     #[serde(default)]
-    pub framework: Option<String>,
+    pub framework: Option<Framework>,
     #[serde(default)]
     pub created_at: Option<i64>,
     #[serde(default)]
@@ -19,7 +21,352 @@ pub struct Project {
     #[serde(default)]
     pub node_version: Option<String>,
     #[serde(default)]
+    pub paused: Option<bool>,
+    #[serde(default)]
     pub latest_deployments: Option<Vec<DeploymentSummary>>,
+    #[serde(default)]
+    pub link: Option<GitLink>,
+    /// Deployment currently serving each target, keyed by target (`production`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub targets: Option<HashMap<String, Option<DeploymentSummary>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crons: Option<ProjectCrons>,
+    /// Vercel Authentication; `None` when off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_protection: Option<SsoProtection>,
+    /// Password protection; `None` when off. The password itself is never kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_protection: Option<PasswordProtection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trusted_ips: Option<TrustedIps>,
+    /// Protection bypass secrets, keyed by the secret. Never serialized so
+    /// they only leave the daemon through `vercel.bypass_secret`.
+    #[serde(default, skip_serializing)]
+    pub protection_bypass: Option<HashMap<String, ProtectionBypass>>,
+}
+
+impl Project {
+    /// Deployment protection settings.
+    pub fn protection(&self) -> ProjectProtection {
+        ProjectProtection {
+            sso_protection: self.sso_protection.clone(),
+            password_protection: self.password_protection.clone(),
+            trusted_ips: self.trusted_ips.clone(),
+        }
+    }
+
+    /// ID of the deployment serving production. After a rollback or promote
+    /// this need not be the newest production deployment.
+    pub fn production_deployment_id(&self) -> Option<&str> {
+        self.production_target().map(|d| d.id.as_str())
+    }
+
+    /// Domains the production deployment is served on, if the project
+    /// record says which deployment that is.
+    pub fn production_domains(&self) -> Option<&[String]> {
+        self.production_target()?.alias.as_deref()
+    }
+
+    fn production_target(&self) -> Option<&DeploymentSummary> {
+        self.targets.as_ref()?.get("production")?.as_ref()
+    }
+}
+
+/// Framework preset of a project.
+///
+/// Serialized as Vercel's slugs (e.g. `nextjs`); presets without a variant
+/// are kept as `Other`, so the serde impls are written out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Framework {
+    NextJs,
+    Gatsby,
+    SvelteKit,
+    Nuxt,
+    Astro,
+    Remix,
+    Vite,
+    CreateReactApp,
+    Other(String),
+}
+
+impl Framework {
+    /// Vercel's slug for the preset.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NextJs => "nextjs",
+            Self::Gatsby => "gatsby",
+            Self::SvelteKit => "sveltekit",
+            Self::Nuxt => "nuxtjs",
+            Self::Astro => "astro",
+            Self::Remix => "remix",
+            Self::Vite => "vite",
+            Self::CreateReactApp => "create-react-app",
+            Self::Other(slug) => slug,
+        }
+    }
+}
+
+impl From<String> for Framework {
+    fn from(slug: String) -> Self {
+        match slug.as_str() {
+            "nextjs" => Self::NextJs,
+            "gatsby" => Self::Gatsby,
+            "sveltekit" => Self::SvelteKit,
+            "nuxtjs" => Self::Nuxt,
+            "astro" => Self::Astro,
+            "remix" => Self::Remix,
+            "vite" => Self::Vite,
+            "create-react-app" => Self::CreateReactApp,
+            _ => Self::Other(slug),
+        }
+    }
+}
+
+/// Human-readable name, e.g. "Next.js"; other presets show their slug.
+impl std::fmt::Display for Framework {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::NextJs => "Next.js",
+            Self::Gatsby => "Gatsby",
+            Self::SvelteKit => "SvelteKit",
+            Self::Nuxt => "Nuxt",
+            Self::Astro => "Astro",
+            Self::Remix => "Remix",
+            Self::Vite => "Vite",
+            Self::CreateReactApp => "Create React App",
+            Self::Other(slug) => slug,
+        })
+    }
+}
+
+impl Serialize for Framework {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Framework {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Vercel Authentication settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SsoProtection {
+    /// Which deployments require login, e.g. `preview` or `all`.
+    pub deployment_type: String,
+}
+
+/// Password protection settings, without the password.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordProtection {
+    pub deployment_type: String,
+}
+
+/// Trusted IP allowlist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedIps {
+    pub deployment_type: String,
+    #[serde(default)]
+    pub addresses: Vec<TrustedIpAddress>,
+    /// `additional` (on top of other protection) or `exclusive`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protection_mode: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustedIpAddress {
+    /// IP address or CIDR range.
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// A project's deployment protection; `None` fields are off.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectProtection {
+    pub sso_protection: Option<SsoProtection>,
+    pub password_protection: Option<PasswordProtection>,
+    pub trusted_ips: Option<TrustedIps>,
+}
+
+/// Deployment protection changes. For each setting, `None` leaves it alone
+/// and `Some(None)` turns it off.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtectionUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sso_protection: Option<Option<SsoProtection>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_protection: Option<Option<PasswordProtectionInput>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_ips: Option<Option<TrustedIps>>,
+}
+
+/// Password protection to enable, including the password.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasswordProtectionInput {
+    pub deployment_type: String,
+    pub password: String,
+}
+
+impl std::fmt::Debug for PasswordProtectionInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordProtectionInput")
+            .field("deployment_type", &self.deployment_type)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Metadata of a protection bypass secret.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtectionBypass {
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    #[serde(default)]
+    pub created_by: Option<String>,
+    /// `automation-bypass` for secrets meant for CI and E2E runners.
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+impl ProtectionBypass {
+    pub fn is_automation(&self) -> bool {
+        self.scope.as_deref() == Some("automation-bypass")
+    }
+}
+
+/// Cron jobs of a project, as defined by its production deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCrons {
+    #[serde(default)]
+    pub enabled_at: Option<i64>,
+    /// Set while crons are disabled for the project.
+    #[serde(default)]
+    pub disabled_at: Option<i64>,
+    #[serde(default)]
+    pub updated_at: Option<i64>,
+    /// Deployment the definitions come from.
+    #[serde(default)]
+    pub deployment_id: Option<String>,
+    #[serde(default)]
+    pub definitions: Vec<CronJob>,
+}
+
+impl ProjectCrons {
+    pub fn enabled(&self) -> bool {
+        self.disabled_at.is_none()
+    }
+}
+
+/// Cron job definition from `vercel.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CronJob {
+    pub path: String,
+    /// Five-field cron expression, evaluated in UTC.
+    pub schedule: String,
+    #[serde(default)]
+    pub host: Option<String>,
+}
+
+/// Git repository a project is connected to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitLink {
+    /// `github`, `gitlab`, or `bitbucket`.
+    #[serde(rename = "type", default)]
+    pub link_type: Option<String>,
+    #[serde(default)]
+    pub repo: Option<String>,
+    /// Numeric on GitHub, a string elsewhere; always a string here.
+    #[serde(default, alias = "projectId", deserialize_with = "string_or_number")]
+    pub repo_id: Option<String>,
+    /// Owning user or organization.
+    #[serde(default)]
+    pub org: Option<String>,
+    #[serde(default)]
+    pub default_branch: Option<String>,
+    #[serde(default)]
+    pub production_branch: Option<String>,
+}
+
+/// Accept a string or a number as an optional string.
+fn string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(s)) => Some(s),
+            Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+            _ => None,
+        },
+    )
+}
+
+/// Request body for creating a project.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewProject {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framework: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_repository: Option<GitRepository>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dev_command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install_command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_directory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_directory: Option<String>,
+}
+
+/// Project settings to change; only fields that are set are sent.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdate {
+    /// New project name (rename).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framework: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dev_command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install_command: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_directory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_directory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serverless_function_region: Option<String>,
+}
+
+/// Git repository to link a new project to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitRepository {
+    /// Provider: `github`, `gitlab`, or `bitbucket`.
+    #[serde(rename = "type")]
+    pub repo_type: String,
+    /// Repository path, e.g. `owner/repo`.
+    pub repo: String,
 }
 
 /// Summary deployment info (included in project listing).
@@ -33,6 +380,9 @@ pub struct DeploymentSummary {
     pub ready_state: Option<String>,
     #[serde(default)]
     pub created_at: Option<i64>,
+    /// Domains assigned to the deployment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Vec<String>>,
 }
 
 /// Full Vercel deployment.
@@ -45,7 +395,7 @@ pub struct Deployment {
     pub name: String,
     pub url: String,
     #[serde(default)]
-    pub ready_state: String,
+    pub ready_state: DeploymentState,
     #[serde(default)]
     pub state: Option<String>,
     /// Created timestamp in milliseconds
@@ -65,6 +415,132 @@ pub struct Deployment {
     pub target: Option<String>,
     #[serde(default)]
     pub source: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regions: Vec<String>,
+    /// Build settings the deployment was created with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_settings: Option<DeploymentProjectSettings>,
+}
+
+/// Project build settings recorded on a deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentProjectSettings {
+    #[serde(default)]
+    pub framework: Option<String>,
+}
+
+/// Build state of a deployment (`readyState`).
+///
+/// Serialized as Vercel's upper-case names. States added by Vercel later are
+/// kept as `Unknown` rather than failing the whole response, which is why the
+/// serde impls are written out instead of derived.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeploymentState {
+    Queued,
+    Initializing,
+    Analyzing,
+    Building,
+    Ready,
+    Error,
+    Canceled,
+    /// A state this version doesn't know, or none reported.
+    Unknown(String),
+}
+
+impl Default for DeploymentState {
+    fn default() -> Self {
+        Self::Unknown(String::new())
+    }
+}
+
+impl DeploymentState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Queued => "QUEUED",
+            Self::Initializing => "INITIALIZING",
+            Self::Analyzing => "ANALYZING",
+            Self::Building => "BUILDING",
+            Self::Ready => "READY",
+            Self::Error => "ERROR",
+            Self::Canceled => "CANCELED",
+            Self::Unknown(state) => state,
+        }
+    }
+
+    /// Whether a deployment in this state will no longer change.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Ready | Self::Error | Self::Canceled)
+    }
+}
+
+impl From<String> for DeploymentState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "QUEUED" => Self::Queued,
+            "INITIALIZING" => Self::Initializing,
+            "ANALYZING" => Self::Analyzing,
+            "BUILDING" => Self::Building,
+            "READY" => Self::Ready,
+            "ERROR" => Self::Error,
+            "CANCELED" => Self::Canceled,
+            _ => Self::Unknown(state),
+        }
+    }
+}
+
+impl std::fmt::Display for DeploymentState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for DeploymentState {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DeploymentState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Deployment {
+    /// Whether the deployment has finished building (successfully or not).
+    pub fn is_terminal(&self) -> bool {
+        self.ready_state.is_terminal()
+    }
+
+    /// Git branch the deployment was built from, if it came from a git push.
+    pub fn git_branch(&self) -> Option<&str> {
+        let meta = self.meta.as_ref()?;
+        ["githubCommitRef", "gitlabCommitRef", "bitbucketCommitRef"]
+            .iter()
+            .find_map(|key| meta.get(*key).and_then(|v| v.as_str()))
+    }
+
+    /// Time since the deployment was created.
+    pub fn age(&self) -> Option<Duration> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+        millis_between(self.created?, now.as_millis() as i64)
+    }
+
+    /// Time from creation until the build started.
+    pub fn queue_duration(&self) -> Option<Duration> {
+        millis_between(self.created?, self.building_at?)
+    }
+
+    /// Time from the build starting to the deployment becoming ready.
+    pub fn build_duration(&self) -> Option<Duration> {
+        millis_between(self.building_at?, self.ready?)
+    }
+}
+
+/// Duration between two millisecond timestamps, `None` if `to` is earlier.
+fn millis_between(from: i64, to: i64) -> Option<Duration> {
+    u64::try_from(to - from).ok().map(Duration::from_millis)
 }
 
 /// Deployment creator info.
@@ -93,8 +569,127 @@ pub struct DeploymentEvent {
     pub payload: Option<serde_json::Value>,
 }
 
+impl DeploymentEvent {
+    /// Log line of the event, from `text` or, failing that, `payload.text`.
+    pub fn message(&self) -> Option<&str> {
+        self.text.as_deref().or_else(|| {
+            self.payload
+                .as_ref()
+                .and_then(|p| p.get("text"))
+                .and_then(|t| t.as_str())
+        })
+    }
+}
+
+/// Serverless function produced by a deployment's build.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Function {
+    pub name: String,
+    /// First region the function is deployed to.
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(default)]
+    pub runtime: Option<String>,
+    /// Memory in MB.
+    #[serde(default)]
+    pub memory: Option<u32>,
+    /// Maximum execution time in seconds.
+    #[serde(default)]
+    pub max_duration: Option<u32>,
+}
+
+/// Deployment check (a CI/CD gate registered by an integration).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentCheck {
+    pub id: String,
+    pub name: String,
+    /// `registered`, `running`, or `completed`.
+    pub status: String,
+    /// `succeeded`, `failed`, `neutral`, `skipped`, `canceled`, or `stale`;
+    /// set once completed.
+    #[serde(default)]
+    pub conclusion: Option<String>,
+    /// Whether a failure blocks promoting the deployment.
+    #[serde(default)]
+    pub blocking: bool,
+    #[serde(default)]
+    pub rerequestable: bool,
+    #[serde(default)]
+    pub details_url: Option<String>,
+    #[serde(default)]
+    pub started_at: Option<i64>,
+    #[serde(default)]
+    pub completed_at: Option<i64>,
+    /// Check output, e.g. Lighthouse `metrics`.
+    #[serde(default)]
+    pub output: Option<serde_json::Value>,
+}
+
+impl DeploymentCheck {
+    /// Completed without a failure.
+    pub fn passed(&self) -> bool {
+        matches!(
+            self.conclusion.as_deref(),
+            Some("succeeded" | "neutral" | "skipped")
+        )
+    }
+
+    /// Completed with a failure or was canceled.
+    pub fn failed(&self) -> bool {
+        matches!(self.conclusion.as_deref(), Some("failed" | "canceled"))
+    }
+}
+
+/// Entry in a deployment's source file tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileTree {
+    pub name: String,
+    /// `directory`, `file`, `symlink`, `lambda`, `middleware`, or `invalid`.
+    #[serde(rename = "type")]
+    pub file_type: String,
+    /// File ID used to fetch the contents; absent for directories.
+    #[serde(default)]
+    pub uid: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<FileTree>,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    #[serde(default)]
+    pub mode: Option<u32>,
+    /// Link target, for symlinks.
+    #[serde(default)]
+    pub symlink: Option<String>,
+}
+
+impl FileTree {
+    /// Find the entry at a `/`-separated path below `entries`.
+    pub fn find<'a>(entries: &'a [FileTree], path: &str) -> Option<&'a FileTree> {
+        let mut parts = path.split('/').filter(|p| !p.is_empty());
+        let mut entry = entries
+            .iter()
+            .find(|e| Some(e.name.as_str()) == parts.next())?;
+        for part in parts {
+            entry = entry.children.iter().find(|e| e.name == part)?;
+        }
+        Some(entry)
+    }
+
+    /// Number of non-directory entries in this subtree.
+    pub fn file_count(&self) -> usize {
+        if self.file_type == "directory" {
+            self.children.iter().map(FileTree::file_count).sum()
+        } else {
+            1
+        }
+    }
+}
+
 /// Vercel user info.
-#[allow(dead_code)]
+///
+/// Optional fields serialize as `null` rather than being omitted.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
@@ -105,20 +700,324 @@ pub struct User {
     pub name: Option<String>,
     #[serde(default)]
     pub username: Option<String>,
+    /// Avatar file ID.
+    #[serde(default)]
+    pub avatar: Option<String>,
+    #[serde(default)]
+    pub default_team_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+}
+
+/// Vercel team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub id: String,
+    pub slug: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    #[serde(default)]
+    pub membership: Option<TeamMembership>,
+}
+
+/// The current user's membership in a team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembership {
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+/// Member of a team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMember {
+    pub uid: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// One of [`TEAM_ROLES`].
+    #[serde(default)]
+    pub role: Option<String>,
+    /// False while an invite is pending.
+    #[serde(default)]
+    pub confirmed: Option<bool>,
+    /// When the member joined (ms since epoch).
+    #[serde(default)]
+    pub created_at: Option<i64>,
+}
+
+impl TeamMember {
+    pub fn is_owner(&self) -> bool {
+        self.role.as_deref() == Some("OWNER")
+    }
+}
+
+/// Roles a team member can have.
+pub const TEAM_ROLES: &[&str] = &[
+    "OWNER",
+    "MEMBER",
+    "DEVELOPER",
+    "VIEWER",
+    "BILLING",
+    "CONTRIBUTOR",
+];
+
+/// Roles a project member can have.
+pub const PROJECT_ROLES: &[&str] = &["ADMIN", "PROJECT_DEVELOPER", "PROJECT_VIEWER"];
+
+/// Member of a project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMember {
+    pub uid: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    /// One of [`PROJECT_ROLES`].
+    #[serde(default)]
+    pub role: Option<String>,
+    /// When the member was added (ms since epoch).
+    #[serde(default, alias = "createdAt")]
+    pub joined_at: Option<i64>,
+}
+
+/// Project environment variable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvVar {
+    pub id: String,
+    pub key: String,
+    /// Absent for sensitive vars, and ciphertext for encrypted ones unless decrypted.
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(rename = "type", default)]
+    pub env_type: Option<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub target: Vec<String>,
+    #[serde(default)]
+    pub git_branch: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    #[serde(default)]
+    pub updated_at: Option<i64>,
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+impl EnvVar {
+    /// Whether the value is a secret that should not be shown by default.
+    pub fn is_secret(&self) -> bool {
+        matches!(self.env_type.as_deref(), Some("encrypted" | "sensitive"))
+    }
+}
+
+/// Accept either a single item or an array (Vercel uses both, e.g. for `target`).
+fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(OneOrMany::One(s)) => vec![s],
+        Some(OneOrMany::Many(v)) => v,
+    })
+}
+
+/// Request body item for creating or updating an environment variable.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvVarInput {
+    pub key: String,
+    pub value: String,
+    pub target: Vec<String>,
+    #[serde(rename = "type")]
+    pub env_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_branch: Option<String>,
+}
+
+/// Result of a bulk env var write: per-item successes and failures.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EnvBulkResult {
+    /// A single object when only one var was sent, hence `one_or_many`.
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub created: Vec<EnvVar>,
+    #[serde(default)]
+    pub failed: Vec<serde_json::Value>,
+}
+
+/// Deployment alias (a hostname pointing at a deployment).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alias {
+    pub uid: String,
+    pub alias: String,
+    #[serde(default)]
+    pub deployment_id: Option<String>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    #[serde(default)]
+    pub redirect: Option<String>,
+}
+
+/// Deploy hook: a URL that triggers a deployment of a git ref when called.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeployHook {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    pub url: String,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+}
+
+/// Webhook that notifies a URL of account or project events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub id: String,
+    pub url: String,
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Projects the webhook is limited to; empty means all projects.
+    #[serde(default)]
+    pub project_ids: Vec<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    /// Signing secret, only returned when the webhook is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+/// Legacy Vercel Secret, referenced by env vars as `@name`. Values are
+/// write-only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Secret {
+    pub uid: String,
+    pub name: String,
+    /// Creation time as an RFC 3339 date.
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+}
+
+/// Edge Config store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeConfig {
+    pub id: String,
+    #[serde(default)]
+    pub slug: Option<String>,
+    /// Changes whenever the items change.
+    #[serde(default)]
+    pub digest: Option<String>,
+    #[serde(default)]
+    pub item_count: Option<u64>,
+    #[serde(default)]
+    pub size_in_bytes: Option<u64>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    #[serde(default)]
+    pub updated_at: Option<i64>,
+}
+
+/// Key/value item in an Edge Config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeConfigItem {
+    pub key: String,
+    pub value: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Domain registered with or added to the account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainInfo {
+    pub id: String,
+    pub name: String,
+    /// `zeit.world` when Vercel serves DNS, `external` otherwise.
+    #[serde(default)]
+    pub service_type: Option<String>,
+    #[serde(default)]
+    pub verified: Option<bool>,
+    /// Nameservers currently set at the registrar.
+    #[serde(default)]
+    pub nameservers: Vec<String>,
+    /// Nameservers Vercel expects for DNS it serves.
+    #[serde(default)]
+    pub intended_nameservers: Vec<String>,
+    /// Registration expiry (ms since epoch); only known for domains bought
+    /// through Vercel.
+    #[serde(default)]
+    pub expires_at: Option<i64>,
+    /// Whether Vercel renews the registration automatically.
+    #[serde(default)]
+    pub renew: Option<bool>,
+    #[serde(default)]
+    pub bought_at: Option<i64>,
+    #[serde(default)]
+    pub transferred_at: Option<i64>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+}
+
+impl DomainInfo {
+    /// `vercel` for domains bought or transferred in, `external` otherwise.
+    pub fn registrar(&self) -> &'static str {
+        if self.bought_at.is_some() || self.transferred_at.is_some() {
+            "vercel"
+        } else {
+            "external"
+        }
+    }
+
+    /// Whole days until the registration expires, negative once expired.
+    pub fn expires_in_days(&self, now_ms: i64) -> Option<i64> {
+        self.expires_at
+            .map(|expires| (expires - now_ms).div_euclid(86_400_000))
+    }
 }
 
 /// Paginated response wrapper.
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct PaginatedResponse<T> {
-    #[serde(alias = "projects", alias = "deployments")]
+    #[serde(
+        alias = "projects",
+        alias = "deployments",
+        alias = "teams",
+        alias = "members",
+        alias = "domains"
+    )]
     pub items: Vec<T>,
     #[serde(default)]
     pub pagination: Option<Pagination>,
 }
 
 /// Pagination info.
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pagination {
     #[serde(default)]
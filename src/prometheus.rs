@@ -0,0 +1,153 @@
+//! Prometheus scrape endpoint for `start --metrics-addr`.
+//!
+//! Calls are recorded through the `metrics` facade and rendered by
+//! `metrics-exporter-prometheus` on `GET /metrics`.
+
+use anyhow::{Context, Result};
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use std::net::{SocketAddr, TcpListener};
+use std::time::Duration;
+
+/// How often histogram samples are folded into the rendered quantiles.
+const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Bind the scrape address. Done before daemonizing so a bad address or a
+/// port in use is reported to the user; the socket survives the fork.
+pub fn bind(addr: SocketAddr) -> Result<TcpListener> {
+    TcpListener::bind(addr).with_context(|| format!("Failed to bind metrics address {}", addr))
+}
+
+/// Install the global metrics recorder and serve `/metrics` from `listener`
+/// on a background thread.
+///
+/// Must run after daemonizing, since it starts a thread.
+pub fn serve(listener: TcpListener) -> Result<()> {
+    let handle = PrometheusBuilder::new()
+        .install_recorder()
+        .context("Failed to install metrics recorder")?;
+    describe();
+
+    listener
+        .set_nonblocking(true)
+        .context("Failed to configure metrics listener")?;
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to create metrics runtime")?;
+
+    std::thread::Builder::new()
+        .name("metrics".into())
+        .spawn(move || {
+            runtime.block_on(async move {
+                let listener = match tokio::net::TcpListener::from_std(listener) {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        tracing::error!("Metrics endpoint disabled: {}", e);
+                        return;
+                    }
+                };
+
+                let upkeep = handle.clone();
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(UPKEEP_INTERVAL);
+                    loop {
+                        interval.tick().await;
+                        upkeep.run_upkeep();
+                    }
+                });
+
+                loop {
+                    let stream = match listener.accept().await {
+                        Ok((stream, _)) => stream,
+                        Err(e) => {
+                            tracing::warn!("Failed to accept metrics connection: {}", e);
+                            continue;
+                        }
+                    };
+                    let handle = handle.clone();
+                    tokio::spawn(async move {
+                        let service = service_fn(move |request| {
+                            let response = scrape(&handle, &request);
+                            async move { Ok::<_, std::convert::Infallible>(response) }
+                        });
+                        if let Err(e) = http1::Builder::new()
+                            .serve_connection(TokioIo::new(stream), service)
+                            .await
+                        {
+                            tracing::debug!("Metrics connection error: {}", e);
+                        }
+                    });
+                }
+            });
+        })
+        .context("Failed to start metrics thread")?;
+
+    Ok(())
+}
+
+/// Answer a scrape request.
+fn scrape(handle: &PrometheusHandle, request: &Request<Incoming>) -> Response<Full<Bytes>> {
+    let (status, body) = match (request.method(), request.uri().path()) {
+        (&Method::GET, "/metrics") => (StatusCode::OK, handle.render()),
+        (_, "/metrics") => (StatusCode::METHOD_NOT_ALLOWED, String::new()),
+        _ => (StatusCode::NOT_FOUND, String::new()),
+    };
+
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    if status == StatusCode::OK {
+        response.headers_mut().insert(
+            hyper::header::CONTENT_TYPE,
+            hyper::header::HeaderValue::from_static("text/plain; version=0.0.4"),
+        );
+    }
+    response
+}
+
+fn describe() {
+    metrics::describe_counter!(
+        "vercel_api_requests_total",
+        "Daemon method calls, by method and status (ok or error)"
+    );
+    metrics::describe_histogram!(
+        "vercel_api_duration_seconds",
+        metrics::Unit::Seconds,
+        "Time to handle a daemon method call, including Vercel API requests"
+    );
+    metrics::describe_gauge!(
+        "vercel_daemon_active_connections",
+        "Client requests currently being handled"
+    );
+}
+
+/// Record a finished call to `method`.
+pub fn record(method: &str, ok: bool, duration: Duration) {
+    let method = method.to_string();
+    let status = if ok { "ok" } else { "error" };
+    metrics::counter!("vercel_api_requests_total", "method" => method.clone(), "status" => status)
+        .increment(1);
+    metrics::histogram!("vercel_api_duration_seconds", "method" => method)
+        .record(duration.as_secs_f64());
+}
+
+/// Counts a request in `vercel_daemon_active_connections` while alive.
+pub struct ActiveRequest;
+
+impl ActiveRequest {
+    pub fn start() -> Self {
+        metrics::gauge!("vercel_daemon_active_connections").increment(1.0);
+        Self
+    }
+}
+
+impl Drop for ActiveRequest {
+    fn drop(&mut self) {
+        metrics::gauge!("vercel_daemon_active_connections").decrement(1.0);
+    }
+}
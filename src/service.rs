@@ -1,30 +1,215 @@
 //! FGP service implementation for Vercel.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use fgp_daemon::service::{HealthStatus, MethodInfo, ParamInfo};
 use fgp_daemon::FgpService;
+use futures_util::StreamExt;
+use regex::{Regex, RegexBuilder};
 use serde_json::Value;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 use tokio::runtime::Runtime;
 
-use crate::api::VercelClient;
+use crate::api::{
+    CachingVercelClient, CollectedPages, DeploymentListOptions, DeploymentWait, VercelApiError,
+    VercelClient,
+};
+use crate::dotenv;
+use crate::metrics::Metrics;
+use crate::models::{
+    Deployment, DeploymentCheck, DeploymentEvent, DeploymentState, DomainInfo, EnvVar, EnvVarInput,
+    FileTree, GitRepository, NewProject, PasswordProtectionInput, ProjectUpdate, ProtectionUpdate,
+    SsoProtection, TrustedIpAddress, TrustedIps, PROJECT_ROLES, TEAM_ROLES,
+};
+
+/// Build log events attached to a failed `vercel.wait_for_deployment` result.
+const WAIT_ERROR_LOG_EVENTS: usize = 50;
+
+/// Default `max_bytes` for `vercel.file` contents.
+const DEFAULT_FILE_MAX_BYTES: i64 = 1024 * 1024;
+
+/// Default cap on items returned by `all: true` listings.
+const DEFAULT_MAX_ITEMS: i64 = 1000;
+
+/// Build log lines counted as warnings by `vercel.build_summary`.
+static BUILD_WARNING: LazyLock<Regex> = LazyLock::new(|| {
+    RegexBuilder::new(r"\bwarn(ing)?\b")
+        .case_insensitive(true)
+        .build()
+        .expect("valid regex")
+});
+
+/// Build log lines counted as errors by `vercel.build_summary`.
+static BUILD_ERROR: LazyLock<Regex> = LazyLock::new(|| {
+    RegexBuilder::new(r"\berr(or)?\b")
+        .case_insensitive(true)
+        .build()
+        .expect("valid regex")
+});
+
+/// Exit code reported at the end of a failed build.
+static BUILD_EXIT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"exited with (-?\d+)").expect("valid regex"));
+
+/// Deployment ready states accepted by the `state` filter of `vercel.deployments`.
+const DEPLOYMENT_STATES: &[&str] = &[
+    "BUILDING",
+    "ERROR",
+    "INITIALIZING",
+    "QUEUED",
+    "READY",
+    "CANCELED",
+];
+
+/// Deployment types accepted by the settings of `vercel.protection_set`.
+const PROTECTION_DEPLOYMENT_TYPES: &[&str] = &[
+    "all",
+    "preview",
+    "production",
+    "prod_deployment_urls_and_all_previews",
+    "all_except_custom_domains",
+];
+
+/// Webhook events accepted by `vercel.webhook_create`.
+const WEBHOOK_EVENTS: &[&str] = &[
+    "deployment.created",
+    "deployment.succeeded",
+    "deployment.ready",
+    "deployment.promoted",
+    "deployment.error",
+    "deployment.canceled",
+    "deployment.check-rerequested",
+    "deployment.integration.action.start",
+    "deployment.integration.action.cancel",
+    "deployment.integration.action.cleanup",
+    "domain.created",
+    "project.created",
+    "project.removed",
+    "integration-configuration.removed",
+    "integration-configuration.permission-upgraded",
+    "integration-configuration.scope-change-confirmed",
+];
+
+/// Project settings accepted by `vercel.update_project`.
+const UPDATABLE_PROJECT_FIELDS: &[&str] = &[
+    "name",
+    "framework",
+    "build_command",
+    "dev_command",
+    "install_command",
+    "output_directory",
+    "root_directory",
+    "node_version",
+    "serverless_function_region",
+];
+
+/// Error for a method name `route` does not know, kept distinct so such
+/// calls stay out of the latency metrics.
+#[derive(Debug)]
+struct UnknownMethod(String);
+
+impl std::fmt::Display for UnknownMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown method: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownMethod {}
+
+/// Team member given by user ID or email.
+enum MemberRef {
+    Uid(String),
+    Email(String),
+}
+
+/// Content filters for `vercel.logs`, applied after fetching.
+struct EventFilter {
+    /// Keep only these event types (e.g. `stderr`); empty keeps all.
+    event_types: Vec<String>,
+    /// Keep only events whose message matches.
+    pattern: Option<regex::Regex>,
+    /// Keep only the last N matching events.
+    tail: Option<usize>,
+}
+
+impl EventFilter {
+    fn from_params(params: &HashMap<String, Value>) -> Result<Self> {
+        let event_types = VercelService::get_param_str(params, "event_type")
+            .map(|s| {
+                s.split(',')
+                    .map(|t| t.trim().to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // A plain `grep` is a case-insensitive substring; `regex: true` uses it as written.
+        let pattern = match VercelService::get_param_str(params, "grep") {
+            Some(grep) if VercelService::get_param_bool(params, "regex", false) => Some(
+                regex::Regex::new(grep)
+                    .map_err(|e| anyhow::anyhow!("Invalid grep regex {:?}: {}", grep, e))?,
+            ),
+            Some(grep) => Some(
+                regex::RegexBuilder::new(&regex::escape(grep))
+                    .case_insensitive(true)
+                    .build()?,
+            ),
+            None => None,
+        };
+
+        let tail = match VercelService::get_param_i64(params, "tail") {
+            Some(n) if n <= 0 => anyhow::bail!("tail must be positive (got {})", n),
+            n => n.map(|n| n as usize),
+        };
+
+        Ok(Self {
+            event_types,
+            pattern,
+            tail,
+        })
+    }
+
+    fn apply(&self, events: Vec<DeploymentEvent>) -> Vec<DeploymentEvent> {
+        let mut events: Vec<DeploymentEvent> = events
+            .into_iter()
+            .filter(|e| self.event_types.is_empty() || self.event_types.contains(&e.event_type))
+            .filter(|e| match &self.pattern {
+                Some(pattern) => e.message().is_some_and(|m| pattern.is_match(m)),
+                None => true,
+            })
+            .collect();
+        if let Some(tail) = self.tail {
+            let skip = events.len().saturating_sub(tail);
+            events.drain(..skip);
+        }
+        events
+    }
+}
 
 /// FGP service for Vercel operations.
 pub struct VercelService {
-    client: Arc<VercelClient>,
+    client: Arc<CachingVercelClient>,
     runtime: Runtime,
+    /// Config profile the access token is read from on reload.
+    profile: String,
+    /// Latency of recent calls, per method.
+    metrics: Metrics,
 }
 
 impl VercelService {
-    /// Create a new VercelService with the given access token.
-    pub fn new(token: String) -> Result<Self> {
-        let client = VercelClient::new(token)?;
+    /// Create a new VercelService around a configured client.
+    ///
+    /// With `cache`, project listings and lookups are served from a short-lived
+    /// in-memory cache.
+    pub fn new(client: VercelClient, profile: String, cache: bool) -> Result<Self> {
         let runtime = Runtime::new()?;
 
         Ok(Self {
-            client: Arc::new(client),
+            client: Arc::new(CachingVercelClient::new(client, cache)),
             runtime,
+            profile,
+            metrics: Metrics::new(),
         })
     }
 
@@ -37,11 +222,109 @@ impl VercelService {
             .unwrap_or(default)
     }
 
+    /// Helper to get an optional i64 parameter.
+    fn get_param_i64(params: &HashMap<String, Value>, key: &str) -> Option<i64> {
+        params.get(key).and_then(|v| v.as_i64())
+    }
+
+    /// Helper to get a timestamp parameter given as epoch milliseconds or RFC3339.
+    fn get_param_time(params: &HashMap<String, Value>, key: &str) -> Result<Option<i64>> {
+        match params.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::Number(n)) => n.as_i64().map(Some).ok_or_else(|| {
+                anyhow::anyhow!("Invalid {}: {} is not a millisecond timestamp", key, n)
+            }),
+            Some(Value::String(s)) => {
+                if let Ok(ms) = s.parse::<i64>() {
+                    return Ok(Some(ms));
+                }
+                chrono::DateTime::parse_from_rfc3339(s)
+                    .map(|t| Some(t.timestamp_millis()))
+                    .map_err(|_| {
+                        anyhow::anyhow!(
+                            "Invalid {}: {:?} (expected milliseconds or an RFC3339 timestamp)",
+                            key,
+                            s
+                        )
+                    })
+            }
+            Some(other) => anyhow::bail!(
+                "Invalid {}: {} (expected milliseconds or an RFC3339 timestamp)",
+                key,
+                other
+            ),
+        }
+    }
+
+    /// Helper to get a boolean parameter with default.
+    fn get_param_bool(params: &HashMap<String, Value>, key: &str, default: bool) -> bool {
+        params.get(key).and_then(|v| v.as_bool()).unwrap_or(default)
+    }
+
+    /// Helper to get the optional `team_id` scope parameter.
+    fn get_team_id(params: &HashMap<String, Value>) -> Option<String> {
+        Self::get_param_str(params, "team_id").map(|s| s.to_string())
+    }
+
     /// Helper to get a string parameter.
     fn get_param_str<'a>(params: &'a HashMap<String, Value>, key: &str) -> Option<&'a str> {
         params.get(key).and_then(|v| v.as_str())
     }
 
+    /// Helper to get a list-of-strings parameter, given as an array or a
+    /// single string.
+    fn get_param_str_list(params: &HashMap<String, Value>, key: &str) -> Result<Vec<String>> {
+        match params.get(key) {
+            None | Some(Value::Null) => Ok(Vec::new()),
+            Some(Value::String(s)) => Ok(vec![s.clone()]),
+            Some(Value::Array(items)) => items
+                .iter()
+                .map(|item| {
+                    item.as_str()
+                        .map(|s| s.to_string())
+                        .ok_or_else(|| anyhow::anyhow!("{} entries must be strings", key))
+                })
+                .collect(),
+            Some(_) => anyhow::bail!("{} must be a string or array of strings", key),
+        }
+    }
+
+    /// Schema entry for the optional `team_id` scope parameter.
+    fn team_id_param() -> ParamInfo {
+        ParamInfo {
+            name: "team_id".into(),
+            param_type: "string".into(),
+            required: false,
+            default: None,
+        }
+    }
+
+    /// Rewrite well-known Vercel API errors about `subject` into targeted messages.
+    fn explain_api_error(err: anyhow::Error, subject: &str) -> anyhow::Error {
+        match err.downcast_ref::<VercelApiError>() {
+            Some(api) if api.is_not_found() => anyhow::anyhow!("{} does not exist", subject),
+            Some(api) if api.is_forbidden() => anyhow::anyhow!(
+                "Access to {} denied: {} (check the token's scope and team_id)",
+                subject,
+                api.message()
+            ),
+            _ => err,
+        }
+    }
+
+    /// Reload implementation: re-read the access token and swap it in place.
+    fn reload(&self) -> Result<Value> {
+        let token = crate::config::get_vercel_token(&self.profile)?;
+        let token_changed = self.client.set_token(token);
+        tracing::info!(token_changed, profile = %self.profile, "Reloaded Vercel access token");
+
+        Ok(serde_json::json!({
+            "reloaded": true,
+            "token_changed": token_changed,
+            "profile": self.profile,
+        }))
+    }
+
     /// Health check implementation.
     fn health(&self) -> Result<Value> {
         let client = self.client.clone();
@@ -50,6 +333,8 @@ impl VercelService {
         Ok(serde_json::json!({
             "status": if ok { "healthy" } else { "unhealthy" },
             "api_connected": ok,
+            "rate_limit": self.client.rate_limit_state(),
+            "metrics": self.metrics.snapshot(),
             "version": env!("CARGO_PKG_VERSION"),
         }))
     }
@@ -57,15 +342,32 @@ impl VercelService {
     /// List projects implementation.
     fn list_projects(&self, params: HashMap<String, Value>) -> Result<Value> {
         let limit = Self::get_param_i32(&params, "limit", 20);
+        // `next` is accepted as a synonym so callers can pass `next_cursor` back as-is.
+        let until =
+            Self::get_param_i64(&params, "until").or_else(|| Self::get_param_i64(&params, "next"));
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let projects = self
-            .runtime
-            .block_on(async move { client.list_projects(Some(limit)).await })?;
+        if Self::get_param_bool(&params, "all", false) {
+            let max_items = Self::get_max_items(&params)?;
+            let collected = self.runtime.block_on(async move {
+                client
+                    .list_projects_all(max_items, until, team_id.as_deref())
+                    .await
+            });
+            return Self::collected_json("projects", collected, max_items);
+        }
+
+        let page = self.runtime.block_on(async move {
+            client
+                .list_projects_page(Some(limit), until, team_id.as_deref())
+                .await
+        })?;
 
         Ok(serde_json::json!({
-            "projects": projects,
-            "count": projects.len(),
+            "projects": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.pagination.and_then(|p| p.next),
         }))
     }
 
@@ -76,213 +378,5681 @@ impl VercelService {
             .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id or name"))?
             .to_string();
 
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
+        let subject = format!("project {}", project_id);
         let project = self
             .runtime
-            .block_on(async move { client.get_project(&project_id).await })?;
+            .block_on(async move { client.get_project(&project_id, team_id.as_deref()).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
 
         Ok(serde_json::to_value(project)?)
     }
 
-    /// List deployments implementation.
-    fn list_deployments(&self, params: HashMap<String, Value>) -> Result<Value> {
-        let project_id = Self::get_param_str(&params, "project_id").map(|s| s.to_string());
+    /// List project members implementation.
+    fn list_project_members(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
         let limit = Self::get_param_i32(&params, "limit", 20);
+        let until =
+            Self::get_param_i64(&params, "until").or_else(|| Self::get_param_i64(&params, "next"));
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let deployments = self.runtime.block_on(async move {
-            client
-                .list_deployments(project_id.as_deref(), Some(limit))
-                .await
-        })?;
+        let subject = format!("project {}", project_id);
+        if Self::get_param_bool(&params, "all", false) {
+            let max_items = Self::get_max_items(&params)?;
+            let collected = self.runtime.block_on(async move {
+                client
+                    .list_project_members_all(&project_id, max_items, until, team_id.as_deref())
+                    .await
+            });
+            return Self::collected_json("members", collected, max_items);
+        }
+
+        let page = self
+            .runtime
+            .block_on(async move {
+                client
+                    .list_project_members_page(&project_id, Some(limit), until, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
 
         Ok(serde_json::json!({
-            "deployments": deployments,
-            "count": deployments.len(),
+            "members": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.pagination.and_then(|p| p.next),
         }))
     }
 
-    /// Get single deployment implementation.
-    fn get_deployment(&self, params: HashMap<String, Value>) -> Result<Value> {
-        let deployment_id = Self::get_param_str(&params, "deployment_id")
-            .or_else(|| Self::get_param_str(&params, "id"))
-            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+    /// Add project member implementation.
+    ///
+    /// Takes a `uid` or an `email`, which is resolved through the team's
+    /// member list. The response carries the role the project reports back.
+    fn add_project_member(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
+        let member = Self::get_project_member_param(&params)?;
+        let role = Self::get_param_str(&params, "role")
+            .unwrap_or("PROJECT_DEVELOPER")
+            .to_ascii_uppercase();
+        if !PROJECT_ROLES.contains(&role.as_str()) {
+            anyhow::bail!(
+                "Invalid role: {} (expected one of {})",
+                role,
+                PROJECT_ROLES.join(", ")
+            );
+        }
+        let team_id = self.require_team_id(&params)?;
+        let client = self.client.clone();
+
+        let subject = format!("project {}", project_id);
+        self.runtime
+            .block_on(async move {
+                let uid = Self::resolve_member_uid(&client, &team_id, member).await?;
+                client
+                    .add_project_member(&project_id, &uid, &role, Some(&team_id))
+                    .await?;
+
+                let members = client
+                    .list_project_members_all(&project_id, usize::MAX, None, Some(&team_id))
+                    .await;
+                let added = members.items.into_iter().find(|m| m.uid == uid);
+                Ok(serde_json::json!({
+                    "added": true,
+                    "project_id": project_id,
+                    "uid": uid,
+                    "email": added.as_ref().and_then(|m| m.email.clone()),
+                    "role": added.and_then(|m| m.role).unwrap_or(role),
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
 
+    /// Remove project member implementation.
+    fn remove_project_member(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let member = Self::get_project_member_param(&params)?;
+        let team_id = self.require_team_id(&params)?;
         let client = self.client.clone();
 
-        let deployment = self
-            .runtime
-            .block_on(async move { client.get_deployment(&deployment_id).await })?;
+        let subject = format!("project {}", project_id);
+        self.runtime
+            .block_on(async move {
+                let uid = Self::resolve_member_uid(&client, &team_id, member).await?;
+                client
+                    .remove_project_member(&project_id, &uid, Some(&team_id))
+                    .await?;
+                Ok(serde_json::json!({
+                    "removed": true,
+                    "project_id": project_id,
+                    "uid": uid,
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
 
-        Ok(serde_json::to_value(deployment)?)
+    /// Helper to get the member of a project member call from `uid` or `email`.
+    fn get_project_member_param(params: &HashMap<String, Value>) -> Result<MemberRef> {
+        match (
+            Self::get_param_str(params, "uid"),
+            Self::get_param_str(params, "email"),
+        ) {
+            (Some(uid), None) => Ok(MemberRef::Uid(uid.to_string())),
+            (None, Some(email)) => Ok(MemberRef::Email(email.to_string())),
+            (Some(_), Some(_)) => anyhow::bail!("Pass either uid or email, not both"),
+            (None, None) => anyhow::bail!("Missing required parameter: uid or email"),
+        }
     }
 
-    /// Get deployment logs/events implementation.
-    fn get_deployment_logs(&self, params: HashMap<String, Value>) -> Result<Value> {
-        let deployment_id = Self::get_param_str(&params, "deployment_id")
-            .or_else(|| Self::get_param_str(&params, "id"))
-            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+    /// Turn a member given by uid or email into a uid, looking emails up in
+    /// the team's member list.
+    async fn resolve_member_uid(
+        client: &CachingVercelClient,
+        team_id: &str,
+        member: MemberRef,
+    ) -> Result<String> {
+        let email = match member {
+            MemberRef::Uid(uid) => return Ok(uid),
+            MemberRef::Email(email) => email,
+        };
+
+        let members = client
+            .list_team_members_all(team_id, usize::MAX, None)
+            .await;
+        if let Some(error) = members.error {
+            anyhow::bail!("Failed to list team members: {}", error);
+        }
+        members
+            .items
+            .into_iter()
+            .find(|m| {
+                m.email
+                    .as_deref()
+                    .is_some_and(|e| e.eq_ignore_ascii_case(&email))
+            })
+            .map(|m| m.uid)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No member of team {} has email {} (invite them with vercel.member_invite first)",
+                    team_id,
+                    email
+                )
+            })
+    }
+
+    /// Git info implementation.
+    fn get_git_info(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
 
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let events = self
+        let subject = format!("project {}", project_id);
+        let link = self
             .runtime
-            .block_on(async move { client.get_deployment_events(&deployment_id).await })?;
+            .block_on(async {
+                client
+                    .get_project_git_info(&project_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
 
         Ok(serde_json::json!({
-            "events": events,
-            "count": events.len(),
+            "project_id": project_id,
+            "connected": link.is_some(),
+            "link": link,
         }))
     }
 
-    /// Get user info implementation.
-    fn get_user(&self) -> Result<Value> {
+    /// Purge cache implementation.
+    ///
+    /// Only the Data Cache can be purged today; `kind` leaves room for more.
+    /// A purge refused during the API's cooldown returns `purged: false` with
+    /// `retry_after_secs` rather than an error.
+    fn purge_cache(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let kind = Self::get_param_str(&params, "kind")
+            .unwrap_or("data")
+            .to_string();
+        if kind != "data" {
+            anyhow::bail!("Invalid kind: {} (expected data)", kind);
+        }
+
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let user = self
-            .runtime
-            .block_on(async move { client.get_user_raw().await })?;
+        let subject = format!("project {}", project_id);
+        let result = self.runtime.block_on(async {
+            client
+                .purge_data_cache(&project_id, team_id.as_deref())
+                .await
+        });
 
-        Ok(user)
+        match result {
+            Ok(response) => Ok(serde_json::json!({
+                "project_id": project_id,
+                "kind": kind,
+                "purged": true,
+                "purged_at": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                "response": response,
+            })),
+            Err(e) => match e.downcast_ref::<VercelApiError>() {
+                Some(api @ VercelApiError::Response { status, .. })
+                    if *status == reqwest::StatusCode::TOO_MANY_REQUESTS =>
+                {
+                    Ok(serde_json::json!({
+                        "project_id": project_id,
+                        "kind": kind,
+                        "purged": false,
+                        "retry_after_secs": api.retry_after().map(|d| d.as_secs()),
+                        "message": api.message(),
+                    }))
+                }
+                _ => Err(Self::explain_api_error(e, &subject)),
+            },
+        }
     }
 
-    /// List env vars implementation.
-    fn list_env_vars(&self, params: HashMap<String, Value>) -> Result<Value> {
+    /// List cron jobs implementation.
+    ///
+    /// Each job gets its previous and next fire time, computed locally in UTC,
+    /// to check whether a cron ran when expected.
+    fn list_crons(&self, params: HashMap<String, Value>) -> Result<Value> {
         let project_id = Self::get_param_str(&params, "project_id")
             .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
-        let target = Self::get_param_str(&params, "target").map(|s| s.to_string());
 
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let result = self
+        let subject = format!("project {}", project_id);
+        let project = self
             .runtime
-            .block_on(async move { client.list_env_vars(&project_id, target.as_deref()).await })?;
+            .block_on(async move { client.get_project(&project_id, team_id.as_deref()).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
 
-        Ok(result)
+        let Some(crons) = project.crons else {
+            return Ok(serde_json::json!({
+                "project_id": project.id,
+                "crons": [],
+                "count": 0,
+                "enabled": false,
+            }));
+        };
+
+        let now = chrono::Utc::now();
+        let enabled = crons.enabled();
+        let jobs: Vec<Value> = crons
+            .definitions
+            .iter()
+            .map(|job| {
+                let mut entry = serde_json::json!({
+                    "path": job.path,
+                    "schedule": job.schedule,
+                    "host": job.host,
+                    "enabled": enabled,
+                });
+                match job.schedule.parse::<croner::Cron>() {
+                    Ok(cron) => {
+                        let fire = |t: Result<chrono::DateTime<chrono::Utc>, _>| {
+                            t.ok()
+                                .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+                        };
+                        entry["previous_run"] =
+                            serde_json::json!(fire(cron.find_previous_occurrence(&now, false)));
+                        if enabled {
+                            entry["next_run"] =
+                                serde_json::json!(fire(cron.find_next_occurrence(&now, false)));
+                        }
+                    }
+                    Err(e) => entry["schedule_error"] = Value::String(e.to_string()),
+                }
+                entry
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "project_id": project.id,
+            "enabled": enabled,
+            "disabled_at": crons.disabled_at,
+            "deployment_id": crons.deployment_id,
+            "count": jobs.len(),
+            "crons": jobs,
+        }))
     }
 
-    /// Set env var implementation.
-    fn set_env_var(&self, params: HashMap<String, Value>) -> Result<Value> {
+    /// Enable or disable cron jobs implementation.
+    fn toggle_crons(&self, params: HashMap<String, Value>) -> Result<Value> {
         let project_id = Self::get_param_str(&params, "project_id")
             .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
-        let key = Self::get_param_str(&params, "key")
-            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: key"))?
+        let enabled = params
+            .get("enabled")
+            .and_then(|v| v.as_bool())
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: enabled (boolean)"))?;
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("project {}", project_id);
+        self.runtime
+            .block_on(async move {
+                client
+                    .set_crons_enabled(&project_id, enabled, team_id.as_deref())
+                    .await?;
+
+                Ok(serde_json::json!({
+                    "project_id": project_id,
+                    "enabled": enabled,
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Get deployment protection implementation.
+    fn get_protection(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
-        let value = Self::get_param_str(&params, "value")
-            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: value"))?
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("project {}", project_id);
+        let protection = self
+            .runtime
+            .block_on(async move { client.get_protection(&project_id, team_id.as_deref()).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::to_value(protection)?)
+    }
+
+    /// Update deployment protection implementation.
+    ///
+    /// Each of `sso`, `password_protection`, and `trusted_ips` is left alone
+    /// when absent and turned off with `"off"`. The password is never echoed.
+    fn set_protection(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
 
-        // Parse target array if provided
-        let target: Option<Vec<String>> =
-            params.get("target").and_then(|v| v.as_array()).map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                    .collect()
-            });
+        let deployment_type = |key: &str| -> Result<Option<Option<String>>> {
+            match params.get(key) {
+                None => Ok(None),
+                Some(Value::Null) => Ok(Some(None)),
+                Some(Value::String(s)) if s == "off" => Ok(Some(None)),
+                Some(Value::String(s)) if PROTECTION_DEPLOYMENT_TYPES.contains(&s.as_str()) => {
+                    Ok(Some(Some(s.clone())))
+                }
+                Some(other) => anyhow::bail!(
+                    "Invalid {}: {} (expected \"off\" or one of {})",
+                    key,
+                    other,
+                    PROTECTION_DEPLOYMENT_TYPES.join(", ")
+                ),
+            }
+        };
 
-        let env_type = Self::get_param_str(&params, "type").map(|s| s.to_string());
+        let sso_protection = deployment_type("sso")?
+            .map(|t| t.map(|deployment_type| SsoProtection { deployment_type }));
 
+        let password = Self::get_param_str(&params, "password");
+        let password_protection = match deployment_type("password_protection")? {
+            Some(Some(deployment_type)) => {
+                let password = password
+                    .filter(|p| !p.is_empty())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("password is required to enable password_protection")
+                    })?
+                    .to_string();
+                Some(Some(PasswordProtectionInput {
+                    deployment_type,
+                    password,
+                }))
+            }
+            Some(None) => Some(None),
+            None if password.is_some() => {
+                anyhow::bail!("password requires password_protection (e.g. \"preview\" or \"all\")")
+            }
+            None => None,
+        };
+
+        let trusted_ips = match params.get("trusted_ips") {
+            None => None,
+            Some(Value::Null) => Some(None),
+            Some(Value::String(s)) if s == "off" => Some(None),
+            Some(_) => {
+                let addresses = Self::get_param_str_list(&params, "trusted_ips")?;
+                if addresses.is_empty() {
+                    anyhow::bail!("trusted_ips must list at least one address (or be \"off\")");
+                }
+                for address in &addresses {
+                    Self::check_cidr(address)?;
+                }
+                let deployment_type = match deployment_type("trusted_ips_deployment_type")? {
+                    Some(Some(t)) => t,
+                    Some(None) => anyhow::bail!("trusted_ips_deployment_type cannot be \"off\""),
+                    None => "all".to_string(),
+                };
+                let protection_mode = Self::get_param_str(&params, "trusted_ips_mode")
+                    .unwrap_or("additional")
+                    .to_string();
+                if !matches!(protection_mode.as_str(), "additional" | "exclusive") {
+                    anyhow::bail!(
+                        "trusted_ips_mode must be \"additional\" or \"exclusive\" (got {:?})",
+                        protection_mode
+                    );
+                }
+                Some(Some(TrustedIps {
+                    deployment_type,
+                    addresses: addresses
+                        .into_iter()
+                        .map(|value| TrustedIpAddress { value, note: None })
+                        .collect(),
+                    protection_mode: Some(protection_mode),
+                }))
+            }
+        };
+
+        let update = ProtectionUpdate {
+            sso_protection,
+            password_protection,
+            trusted_ips,
+        };
+        if update.sso_protection.is_none()
+            && update.password_protection.is_none()
+            && update.trusted_ips.is_none()
+        {
+            anyhow::bail!("Nothing to change: pass sso, password_protection, and/or trusted_ips");
+        }
+        let password_changed = matches!(update.password_protection, Some(Some(_)));
+
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let result = self.runtime.block_on(async move {
-            let target_refs: Option<Vec<&str>> = target
-                .as_ref()
-                .map(|v| v.iter().map(|s| s.as_str()).collect());
-            client
-                .set_env_var(&project_id, &key, &value, target_refs, env_type.as_deref())
-                .await
-        })?;
+        let subject = format!("project {}", project_id);
+        let protection = self
+            .runtime
+            .block_on(async move {
+                client
+                    .update_protection(&project_id, &update, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
 
+        let mut result = serde_json::to_value(protection)?;
+        result["password_changed"] = Value::Bool(password_changed);
         Ok(result)
     }
 
-    /// List domains implementation.
-    fn list_domains(&self, params: HashMap<String, Value>) -> Result<Value> {
+    /// Check that `value` is an IP address or CIDR range.
+    fn check_cidr(value: &str) -> Result<()> {
+        let (ip, prefix) = match value.split_once('/') {
+            Some((ip, prefix)) => (ip, Some(prefix)),
+            None => (value, None),
+        };
+        let ip: std::net::IpAddr = ip
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid trusted IP {:?}: not an IP address", value))?;
+        let max = if ip.is_ipv4() { 32 } else { 128 };
+        match prefix.map(|p| p.parse::<u8>()) {
+            None => Ok(()),
+            Some(Ok(bits)) if bits <= max => Ok(()),
+            Some(_) => anyhow::bail!("Invalid trusted IP {:?}: prefix must be 0-{}", value, max),
+        }
+    }
+
+    /// Protection bypass secret implementation.
+    ///
+    /// `get` lists the secrets, `generate` replaces the automation secret (or
+    /// creates the first one), and `revoke` removes `secret`, or the only
+    /// automation secret when none is given.
+    fn bypass_secret(&self, params: HashMap<String, Value>) -> Result<Value> {
         let project_id = Self::get_param_str(&params, "project_id")
             .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
+        let action = Self::get_param_str(&params, "action")
+            .unwrap_or("get")
+            .to_string();
+        let secret = Self::get_param_str(&params, "secret").map(str::to_string);
+        if !matches!(action.as_str(), "get" | "generate" | "revoke") {
+            anyhow::bail!(
+                "Invalid action: {} (expected get, generate, or revoke)",
+                action
+            );
+        }
 
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let result = self
-            .runtime
-            .block_on(async move { client.list_domains(&project_id).await })?;
+        let subject = format!("project {}", project_id);
+        self.runtime
+            .block_on(async move {
+                let team_id = team_id.as_deref();
+                let current = client.get_protection_bypass(&project_id, team_id).await?;
+                let automation: Vec<&String> = current
+                    .iter()
+                    .filter(|(_, bypass)| bypass.is_automation())
+                    .map(|(secret, _)| secret)
+                    .collect();
 
-        Ok(result)
+                match action.as_str() {
+                    "generate" => {
+                        let previous = automation.first().map(|s| s.as_str());
+                        let updated = client
+                            .generate_protection_bypass(&project_id, previous, team_id)
+                            .await?;
+                        let secret = updated
+                            .iter()
+                            .find(|(secret, bypass)| {
+                                bypass.is_automation() && !current.contains_key(*secret)
+                            })
+                            .map(|(secret, _)| secret.clone())
+                            .ok_or_else(|| {
+                                anyhow::anyhow!("Vercel did not return a new bypass secret")
+                            })?;
+
+                        let mut result = serde_json::json!({
+                            "project_id": project_id,
+                            "secret": secret,
+                            "previous_invalidated": previous.is_some(),
+                        });
+                        if previous.is_some() {
+                            result["warning"] = Value::String(
+                                "The previous bypass secret has been invalidated; update any \
+                                 runners still using it."
+                                    .to_string(),
+                            );
+                        }
+                        Ok(result)
+                    }
+                    "revoke" => {
+                        let target = match (secret, automation.as_slice()) {
+                            (Some(secret), _) if current.contains_key(&secret) => secret,
+                            (Some(_), _) => {
+                                anyhow::bail!("No such bypass secret on project {}", project_id)
+                            }
+                            (None, [only]) => only.to_string(),
+                            (None, []) => {
+                                anyhow::bail!("Project {} has no bypass secret", project_id)
+                            }
+                            (None, _) => anyhow::bail!(
+                                "Project {} has several bypass secrets; pass secret to pick one",
+                                project_id
+                            ),
+                        };
+                        let remaining = client
+                            .revoke_protection_bypass(&project_id, &target, team_id)
+                            .await?;
+                        Ok(serde_json::json!({
+                            "project_id": project_id,
+                            "revoked": target,
+                            "remaining": remaining.len(),
+                        }))
+                    }
+                    _ => {
+                        let mut secrets: Vec<Value> = current
+                            .iter()
+                            .map(|(secret, bypass)| {
+                                serde_json::json!({
+                                    "secret": secret,
+                                    "scope": bypass.scope,
+                                    "created_at": bypass.created_at,
+                                    "created_by": bypass.created_by,
+                                })
+                            })
+                            .collect();
+                        secrets.sort_by_key(|s| s["created_at"].as_i64());
+                        Ok(serde_json::json!({
+                            "project_id": project_id,
+                            "secrets": secrets,
+                            "count": secrets.len(),
+                        }))
+                    }
+                }
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
     }
 
-    /// Redeploy implementation.
-    fn redeploy(&self, params: HashMap<String, Value>) -> Result<Value> {
-        let deployment_id = Self::get_param_str(&params, "deployment_id")
-            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+    /// Project overview implementation.
+    ///
+    /// Fetches the project, its latest production and preview deployments,
+    /// domains, and env var counts concurrently. Only a failed project lookup
+    /// fails the call; any other section reports its own `error` instead.
+    fn project_overview(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
             .to_string();
 
+        let team_id = Self::get_team_id(&params);
         let client = self.client.clone();
 
-        let result = self
-            .runtime
-            .block_on(async move { client.redeploy(&deployment_id).await })?;
+        let subject = format!("project {}", project_id);
+        let (project, production, preview, domains, env_vars) = self.runtime.block_on(async move {
+            let team_id = team_id.as_deref();
+            let latest = |target: &str| DeploymentListOptions {
+                project_id: Some(project_id.clone()),
+                limit: Some(1),
+                target: Some(target.to_string()),
+                ..Default::default()
+            };
+            let (production_options, preview_options) = (latest("production"), latest("preview"));
 
-        Ok(result)
+            futures_util::join!(
+                client.get_project(&project_id, team_id),
+                client.list_deployments(&production_options, team_id),
+                client.list_deployments(&preview_options, team_id),
+                client.list_domains(&project_id, team_id),
+                client.list_env_vars(&project_id, None, false, team_id),
+            )
+        });
+        let project = project.map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        let latest_deployment = |result: Result<Vec<Deployment>>| {
+            Self::overview_section(result.map(|d| d.into_iter().next()))
+        };
+        let domains = Self::overview_section(domains.map(|d| {
+            let domains: Vec<Value> = d["domains"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|d| {
+                    serde_json::json!({
+                        "name": d.get("name"),
+                        "verified": d.get("verified"),
+                        "redirect": d.get("redirect"),
+                        "git_branch": d.get("gitBranch"),
+                    })
+                })
+                .collect();
+            serde_json::json!({ "domains": domains, "count": domains.len() })
+        }));
+        let env_var_counts = Self::overview_section(env_vars.map(|vars| {
+            let count = |target: &str| {
+                vars.iter()
+                    .filter(|v| v.target.iter().any(|t| t == target))
+                    .count()
+            };
+            serde_json::json!({
+                "production": count("production"),
+                "preview": count("preview"),
+                "development": count("development"),
+                "total": vars.len(),
+            })
+        }));
+
+        Ok(serde_json::json!({
+            "project": project,
+            "latest_deployments": {
+                "production": latest_deployment(production),
+                "preview": latest_deployment(preview),
+            },
+            "domains": domains,
+            "env_var_counts": env_var_counts,
+        }))
     }
-}
 
-impl FgpService for VercelService {
-    fn name(&self) -> &str {
-        "vercel"
+    /// Serialize one `vercel.project_overview` section, or report its error.
+    fn overview_section<T: serde::Serialize>(result: Result<T>) -> Value {
+        match result {
+            Ok(value) => serde_json::to_value(value)
+                .unwrap_or_else(|e| serde_json::json!({ "error": e.to_string() })),
+            Err(e) => serde_json::json!({ "error": format!("{:#}", e) }),
+        }
     }
 
-    fn version(&self) -> &str {
-        env!("CARGO_PKG_VERSION")
+    /// Validate a project name against Vercel's naming rules.
+    fn validate_project_name(name: &str) -> Result<()> {
+        if name.is_empty() || name.len() > 100 {
+            anyhow::bail!(
+                "Invalid project name {:?}: must be 1-100 characters (got {})",
+                name,
+                name.len()
+            );
+        }
+        if let Some(c) = name.chars().find(|c| {
+            !(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-'))
+        }) {
+            anyhow::bail!(
+                "Invalid project name {:?}: character {:?} not allowed (use lowercase letters, digits, '.', '_' or '-')",
+                name,
+                c
+            );
+        }
+        if name.contains("---") {
+            anyhow::bail!("Invalid project name {:?}: must not contain '---'", name);
+        }
+        Ok(())
     }
 
-    fn dispatch(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
-        match method {
-            "health" => self.health(),
-            "projects" | "vercel.projects" => self.list_projects(params),
-            "project" | "vercel.project" => self.get_project(params),
-            "deployments" | "vercel.deployments" => self.list_deployments(params),
-            "deployment" | "vercel.deployment" => self.get_deployment(params),
-            "logs" | "vercel.logs" => self.get_deployment_logs(params),
-            "user" | "vercel.user" => self.get_user(),
-            "env_vars" | "vercel.env_vars" => self.list_env_vars(params),
-            "set_env" | "vercel.set_env" => self.set_env_var(params),
-            "domains" | "vercel.domains" => self.list_domains(params),
-            "redeploy" | "vercel.redeploy" => self.redeploy(params),
-            _ => anyhow::bail!("Unknown method: {}", method),
+    /// Create project implementation.
+    fn create_project(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_param_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+        Self::validate_project_name(&name)?;
+
+        // `git_repository` is either "owner/repo" (GitHub) or {"type": ..., "repo": ...}.
+        let git_repository = match params.get("git_repository") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(repo)) => Some(GitRepository {
+                repo_type: "github".into(),
+                repo: repo.clone(),
+            }),
+            Some(value) => Some(
+                serde_json::from_value::<GitRepository>(value.clone()).map_err(|e| {
+                    anyhow::anyhow!("Invalid git_repository (expected {{type, repo}}): {}", e)
+                })?,
+            ),
+        };
+        if Self::get_param_bool(&params, "skip_git", false) && git_repository.is_some() {
+            anyhow::bail!("skip_git: true cannot be combined with git_repository");
         }
+
+        let string_param = |key: &str| Self::get_param_str(&params, key).map(|s| s.to_string());
+        let project = NewProject {
+            name,
+            framework: string_param("framework"),
+            git_repository,
+            build_command: string_param("build_command"),
+            dev_command: string_param("dev_command"),
+            install_command: string_param("install_command"),
+            output_directory: string_param("output_directory"),
+            root_directory: string_param("root_directory"),
+        };
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let project = self
+            .runtime
+            .block_on(async move { client.create_project(&project, team_id.as_deref()).await })?;
+
+        Ok(serde_json::to_value(project)?)
     }
 
-    fn method_list(&self) -> Vec<MethodInfo> {
-        vec![
-            MethodInfo {
-                name: "vercel.projects".into(),
-                description: "List all Vercel projects".into(),
-                params: vec![ParamInfo {
-                    name: "limit".into(),
-                    param_type: "integer".into(),
-                    required: false,
-                    default: Some(serde_json::json!(20)),
-                }],
-            },
-            MethodInfo {
-                name: "vercel.project".into(),
-                description: "Get a specific project by ID or name".into(),
-                params: vec![ParamInfo {
-                    name: "project_id".into(),
-                    param_type: "string".into(),
-                    required: true,
-                    default: None,
-                }],
-            },
-            MethodInfo {
-                name: "vercel.deployments".into(),
-                description: "List deployments (optionally filtered by project)".into(),
+    /// Update project implementation.
+    ///
+    /// Only the settings in `UPDATABLE_PROJECT_FIELDS` may be changed; any other
+    /// param is rejected rather than silently ignored.
+    fn update_project(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+
+        let mut unknown: Vec<&str> = params
+            .keys()
+            .map(|k| k.as_str())
+            .filter(|k| !matches!(*k, "project_id" | "team_id"))
+            .filter(|k| !UPDATABLE_PROJECT_FIELDS.contains(k))
+            .collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            anyhow::bail!(
+                "Unknown project field(s): {}. Allowed: {}",
+                unknown.join(", "),
+                UPDATABLE_PROJECT_FIELDS.join(", ")
+            );
+        }
+
+        let field = |key: &str| -> Result<Option<String>> {
+            match params.get(key) {
+                None => Ok(None),
+                Some(Value::String(s)) => Ok(Some(s.clone())),
+                Some(other) => anyhow::bail!("Parameter {} must be a string, got {}", key, other),
+            }
+        };
+        let update = ProjectUpdate {
+            name: field("name")?,
+            framework: field("framework")?,
+            build_command: field("build_command")?,
+            dev_command: field("dev_command")?,
+            install_command: field("install_command")?,
+            output_directory: field("output_directory")?,
+            root_directory: field("root_directory")?,
+            node_version: field("node_version")?,
+            serverless_function_region: field("serverless_function_region")?,
+        };
+        if !params
+            .keys()
+            .any(|k| UPDATABLE_PROJECT_FIELDS.contains(&k.as_str()))
+        {
+            anyhow::bail!(
+                "No fields to update. Allowed: {}",
+                UPDATABLE_PROJECT_FIELDS.join(", ")
+            );
+        }
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("project {}", project_id);
+        let project = self
+            .runtime
+            .block_on(async move {
+                client
+                    .update_project(&project_id, &update, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::to_value(project)?)
+    }
+
+    /// Pause/unpause project implementation.
+    ///
+    /// Idempotent: asking for the state the project is already in is a no-op
+    /// reported via `already_paused` / `already_unpaused`.
+    fn set_project_paused(&self, params: HashMap<String, Value>, paused: bool) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+        let already_key = if paused {
+            "already_paused"
+        } else {
+            "already_unpaused"
+        };
+
+        self.runtime.block_on(async move {
+            let project = client
+                .get_project(&project_id, team_id.as_deref())
+                .await
+                .map_err(|e| Self::explain_api_error(e, &format!("project {}", project_id)))?;
+
+            let already = project.paused.unwrap_or(false) == paused;
+            if !already {
+                if paused {
+                    client
+                        .pause_project(&project.id, team_id.as_deref())
+                        .await?;
+                } else {
+                    client
+                        .unpause_project(&project.id, team_id.as_deref())
+                        .await?;
+                }
+            }
+
+            Ok(serde_json::json!({
+                "project_id": project.id,
+                "paused": paused,
+                already_key: already,
+            }))
+        })
+    }
+
+    /// Delete project implementation.
+    ///
+    /// Requires `confirm` to equal the project's name as a guard against
+    /// deleting the wrong project.
+    fn delete_project(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let confirm = Self::get_param_str(&params, "confirm").map(|s| s.to_string());
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let project = client
+                .get_project(&project_id, team_id.as_deref())
+                .await
+                .map_err(|e| Self::explain_api_error(e, &format!("project {}", project_id)))?;
+
+            match confirm.as_deref() {
+                Some(name) if name == project.name => {}
+                Some(name) => anyhow::bail!(
+                    "Refusing to delete project {}: confirm {:?} does not match project name {:?}",
+                    project.id,
+                    name,
+                    project.name
+                ),
+                None => anyhow::bail!(
+                    "Refusing to delete project {}: pass confirm: {:?} (the project name)",
+                    project.id,
+                    project.name
+                ),
+            }
+
+            client
+                .delete_project(&project.id, team_id.as_deref())
+                .await?;
+
+            Ok(serde_json::json!({
+                "deleted": true,
+                "project_id": project.id,
+                "name": project.name,
+            }))
+        })
+    }
+
+    /// List deployments implementation.
+    fn list_deployments(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let state = match Self::get_param_str(&params, "state") {
+            Some(state) => Some(Self::parse_deployment_states(state)?),
+            None => None,
+        };
+        let target = Self::get_param_str(&params, "target").map(|s| s.to_string());
+        if let Some(t) = target.as_deref() {
+            if !matches!(t, "production" | "preview") {
+                anyhow::bail!("Invalid target {:?}: expected production or preview", t);
+            }
+        }
+        let options = DeploymentListOptions {
+            project_id: Self::get_param_str(&params, "project_id").map(|s| s.to_string()),
+            limit: Some(Self::get_param_i32(&params, "limit", 20)),
+            state,
+            target,
+            branch: Self::get_param_str(&params, "branch").map(|s| s.to_string()),
+            since: Self::get_param_time(&params, "since")?,
+        };
+        // `until` doubles as the pagination cursor (`next_cursor` is a timestamp),
+        // and `next` is accepted as a synonym for it.
+        let until = match Self::get_param_time(&params, "until")? {
+            Some(until) => Some(until),
+            None => Self::get_param_time(&params, "next")?,
+        };
+        if let (Some(since), Some(until)) = (options.since, until) {
+            if since > until {
+                anyhow::bail!("since ({}) is after until ({})", since, until);
+            }
+        }
+        let since = options.since;
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        if Self::get_param_bool(&params, "all", false) {
+            let max_items = Self::get_max_items(&params)?;
+            let collected = self.runtime.block_on(async move {
+                client
+                    .list_deployments_all(&options, max_items, until, team_id.as_deref())
+                    .await
+            });
+            let mut result = Self::collected_json("deployments", collected, max_items)?;
+            result["range"] = serde_json::json!({ "since": since, "until": until });
+            return Ok(result);
+        }
+
+        let page = self.runtime.block_on(async move {
+            client
+                .list_deployments_page(&options, until, team_id.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "deployments": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.pagination.and_then(|p| p.next),
+            "range": { "since": since, "until": until },
+        }))
+    }
+
+    /// Read the `max_items` cap for `all: true` listings.
+    fn get_max_items(params: &HashMap<String, Value>) -> Result<usize> {
+        let max_items = Self::get_param_i64(params, "max_items").unwrap_or(DEFAULT_MAX_ITEMS);
+        if max_items <= 0 {
+            anyhow::bail!("max_items must be positive (got {})", max_items);
+        }
+        Ok(max_items as usize)
+    }
+
+    /// Shape an `all: true` listing, flagging a hit cap or a failed page.
+    fn collected_json<T: serde::Serialize>(
+        key: &str,
+        collected: CollectedPages<T>,
+        max_items: usize,
+    ) -> Result<Value> {
+        let mut result = serde_json::json!({
+            key: serde_json::to_value(&collected.items)?,
+            "count": collected.items.len(),
+            "next_cursor": collected.next,
+            "complete": !collected.truncated && collected.error.is_none(),
+        });
+        if collected.truncated {
+            result["warning"] = Value::String(format!(
+                "Stopped at max_items ({}); pass next_cursor as `until` to continue",
+                max_items
+            ));
+        }
+        if let Some(error) = collected.error {
+            result["error"] = Value::String(error);
+        }
+        Ok(result)
+    }
+
+    /// Latest deployment implementation: the newest deployment for a project
+    /// matching `target` and `state` ("what's live right now?").
+    fn latest_deployment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let target = Self::get_param_str(&params, "target")
+            .unwrap_or("production")
+            .to_string();
+        if !matches!(target.as_str(), "production" | "preview") {
+            anyhow::bail!(
+                "Invalid target {:?}: expected production or preview",
+                target
+            );
+        }
+        let state = Self::parse_deployment_states(
+            Self::get_param_str(&params, "state").unwrap_or("READY"),
+        )?;
+        let include_domains = Self::get_param_bool(&params, "include_domains", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("project {}", project_id);
+        self.runtime
+            .block_on(async move {
+                let options = DeploymentListOptions {
+                    project_id: Some(project_id.clone()),
+                    limit: Some(1),
+                    state: Some(state.clone()),
+                    target: Some(target.clone()),
+                    ..Default::default()
+                };
+                let latest = client
+                    .list_deployments(&options, team_id.as_deref())
+                    .await?
+                    .into_iter()
+                    .next();
+
+                let Some(deployment) = latest else {
+                    return Ok(serde_json::json!({
+                        "none_found": true,
+                        "project_id": project_id,
+                        "target": target,
+                        "state": state,
+                    }));
+                };
+
+                let mut result = serde_json::json!({
+                    "none_found": false,
+                    "deployment": deployment,
+                });
+                if include_domains {
+                    // Production domains have no git branch; preview domains
+                    // follow the branch the deployment was built from.
+                    let branch = deployment.git_branch();
+                    let domains = client.list_domains(&project_id, team_id.as_deref()).await?;
+                    let domains: Vec<&Value> = domains["domains"]
+                        .as_array()
+                        .map(|d| d.iter().collect())
+                        .unwrap_or_default();
+                    let serving: Vec<&Value> = domains
+                        .into_iter()
+                        .filter(|d| {
+                            let git_branch = d.get("gitBranch").and_then(|v| v.as_str());
+                            match target.as_str() {
+                                "production" => git_branch.is_none(),
+                                _ => git_branch.is_some() && git_branch == branch,
+                            }
+                        })
+                        .collect();
+                    result["domains"] = serde_json::to_value(serving)?;
+                }
+
+                Ok(result)
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Validate a comma-separated `state` filter, normalising to upper case.
+    fn parse_deployment_states(state: &str) -> Result<String> {
+        let states: Vec<String> = state
+            .split(',')
+            .map(|s| s.trim().to_ascii_uppercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+        if states.is_empty() {
+            anyhow::bail!("state filter is empty");
+        }
+        if let Some(bad) = states
+            .iter()
+            .find(|s| !DEPLOYMENT_STATES.contains(&s.as_str()))
+        {
+            anyhow::bail!(
+                "Invalid deployment state {:?}. Allowed: {}",
+                bad,
+                DEPLOYMENT_STATES.join(", ")
+            );
+        }
+        Ok(states.join(","))
+    }
+
+    /// Get single deployment implementation.
+    fn get_deployment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        let deployment = self
+            .runtime
+            .block_on(async move {
+                client
+                    .get_deployment(&deployment_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        let mut result = serde_json::to_value(&deployment)?;
+        result["age_seconds"] = serde_json::json!(deployment.age().map(|d| d.as_secs()));
+        result["build_duration_seconds"] =
+            serde_json::json!(deployment.build_duration().map(|d| d.as_secs_f64()));
+        Ok(result)
+    }
+
+    /// Watch deployment implementation.
+    fn watch_deployment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let poll_interval_ms = Self::get_param_i64(&params, "poll_interval_ms").unwrap_or(5_000);
+        let timeout_ms = Self::get_param_i64(&params, "timeout_ms").unwrap_or(300_000);
+        if poll_interval_ms <= 0 || timeout_ms <= 0 {
+            anyhow::bail!("poll_interval_ms and timeout_ms must be positive");
+        }
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        let deployment = self
+            .runtime
+            .block_on(async move {
+                client
+                    .watch_deployment(
+                        &deployment_id,
+                        Duration::from_millis(poll_interval_ms as u64),
+                        Duration::from_millis(timeout_ms as u64),
+                        team_id.as_deref(),
+                    )
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::to_value(deployment)?)
+    }
+
+    /// Wait for deployment implementation.
+    ///
+    /// Like `watch`, but a timeout is reported in the result rather than as an
+    /// error, and failed builds come back with their last build log events.
+    fn wait_for_deployment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .or_else(|| Self::get_param_str(&params, "url"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let timeout_secs = Self::get_param_i64(&params, "timeout_secs").unwrap_or(600);
+        let poll_interval_secs = Self::get_param_i64(&params, "poll_interval_secs").unwrap_or(5);
+        if timeout_secs <= 0 || poll_interval_secs <= 0 {
+            anyhow::bail!("timeout_secs and poll_interval_secs must be positive");
+        }
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        self.runtime
+            .block_on(async move {
+                let wait = client
+                    .wait_for_deployment(
+                        &deployment_id,
+                        Duration::from_secs(poll_interval_secs as u64),
+                        Duration::from_secs(timeout_secs as u64),
+                        team_id.as_deref(),
+                    )
+                    .await?;
+
+                Self::wait_result(&client, wait, team_id.as_deref()).await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Shape a finished wait: final deployment, timings, and state transitions,
+    /// plus the tail of the build logs when the build failed.
+    async fn wait_result(
+        client: &VercelClient,
+        wait: DeploymentWait,
+        team_id: Option<&str>,
+    ) -> Result<Value> {
+        let transitions: Vec<Value> = wait
+            .transitions
+            .iter()
+            .map(|t| {
+                serde_json::json!({
+                    "state": t.state,
+                    "elapsed_ms": t.elapsed.as_millis() as u64,
+                })
+            })
+            .collect();
+        let build_duration_ms = wait
+            .deployment
+            .build_duration()
+            .filter(|_| wait.deployment.is_terminal())
+            .map(|d| d.as_millis() as u64);
+        let mut result = serde_json::json!({
+            "deployment": wait.deployment,
+            "ready_state": wait.deployment.ready_state,
+            "timed_out": wait.timed_out,
+            "elapsed_ms": wait.elapsed.as_millis() as u64,
+            "build_duration_ms": build_duration_ms,
+            "transitions": transitions,
+        });
+
+        // Save the follow-up `vercel.logs` call when the build failed.
+        if wait.deployment.ready_state == DeploymentState::Error {
+            match client
+                .get_deployment_events(&wait.deployment.uid, team_id)
+                .await
+            {
+                Ok(events) => {
+                    let skip = events.len().saturating_sub(WAIT_ERROR_LOG_EVENTS);
+                    result["build_logs"] = serde_json::to_value(&events[skip..])?;
+                }
+                Err(e) => result["build_logs_error"] = Value::String(format!("{:#}", e)),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Cancel deployment implementation.
+    fn cancel_deployment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            // Cancelling a finished build is an API error; report it as a no-op instead.
+            let current = client
+                .get_deployment(&deployment_id, team_id.as_deref())
+                .await?;
+            if current.is_terminal() {
+                return Ok(serde_json::json!({
+                    "canceled": false,
+                    "already_finished": true,
+                    "ready_state": current.ready_state,
+                    "deployment": current,
+                }));
+            }
+
+            let deployment = client
+                .cancel_deployment(&deployment_id, team_id.as_deref())
+                .await?;
+
+            Ok(serde_json::json!({
+                "canceled": true,
+                "already_finished": false,
+                "ready_state": deployment.ready_state,
+                "deployment": deployment,
+            }))
+        })
+    }
+
+    /// Delete deployment implementation.
+    ///
+    /// Accepts a deployment id or URL. Production deployments additionally
+    /// require `force: true`.
+    fn delete_deployment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_ref = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .or_else(|| Self::get_param_str(&params, "url"))
+            .map(|s| s.to_string())
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id or url"))?;
+
+        if !Self::get_param_bool(&params, "confirm", false) {
+            anyhow::bail!(
+                "Refusing to delete deployment {}: pass confirm: true",
+                deployment_ref
+            );
+        }
+        let force = Self::get_param_bool(&params, "force", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let deployment = client
+                .get_deployment(&deployment_ref, team_id.as_deref())
+                .await?;
+
+            if deployment.target.as_deref() == Some("production") && !force {
+                anyhow::bail!(
+                    "Refusing to delete production deployment {} ({}): pass force: true",
+                    deployment.uid,
+                    deployment.url
+                );
+            }
+
+            let result = client
+                .delete_deployment(&deployment.uid, team_id.as_deref())
+                .await?;
+
+            Ok(serde_json::json!({
+                "deleted": true,
+                "deployment_id": deployment.uid,
+                "url": deployment.url,
+                "target": deployment.target,
+                "state": result["state"],
+            }))
+        })
+    }
+
+    /// Rollback implementation.
+    ///
+    /// Promotes `deployment_id`, or when omitted, the most recent READY
+    /// production deployment before the current one.
+    fn rollback(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let deployment_id = Self::get_param_str(&params, "deployment_id").map(|s| s.to_string());
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let project = client.get_project(&project_id, team_id.as_deref()).await?;
+            let options = DeploymentListOptions {
+                project_id: Some(project_id.clone()),
+                limit: Some(100),
+                state: Some("READY".to_string()),
+                target: Some("production".to_string()),
+                ..Default::default()
+            };
+            let page = client
+                .list_deployments_page(&options, None, team_id.as_deref())
+                .await?;
+
+            // Filter again locally in case the API ignores a filter.
+            let mut production: Vec<Deployment> = page
+                .items
+                .into_iter()
+                .filter(|d| {
+                    d.target.as_deref() == Some("production")
+                        && d.ready_state == DeploymentState::Ready
+                })
+                .collect();
+            production.sort_by_key(|d| std::cmp::Reverse(d.created.unwrap_or(0)));
+
+            // Production is served by the project's production target, which a
+            // previous rollback or promote leaves pointing at an older
+            // deployment. Only projects without one fall back to the newest.
+            let current = match project.production_deployment_id() {
+                Some(id) => match production.iter().find(|d| d.uid == id) {
+                    Some(d) => Some(d.clone()),
+                    None => Some(client.get_deployment(id, team_id.as_deref()).await?),
+                },
+                None => production.first().cloned(),
+            };
+
+            let target = match deployment_id {
+                Some(id) => client.get_deployment(&id, team_id.as_deref()).await?,
+                None => {
+                    // The newest deployment older than the one serving production.
+                    let current_created = current.as_ref().and_then(|c| c.created);
+                    production
+                        .iter()
+                        .find(|d| {
+                            current.as_ref().is_none_or(|c| c.uid != d.uid)
+                                && match (d.created, current_created) {
+                                    (Some(created), Some(current)) => created < current,
+                                    _ => true,
+                                }
+                        })
+                        .cloned()
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Nothing to roll back to: project {} has no READY production deployment older than the current one",
+                                project_id
+                            )
+                        })?
+                }
+            };
+
+            if current.as_ref().is_some_and(|c| c.uid == target.uid) {
+                anyhow::bail!(
+                    "Deployment {} is already the current production deployment",
+                    target.uid
+                );
+            }
+
+            client
+                .promote_deployment(&project_id, &target.uid, team_id.as_deref())
+                .await?;
+
+            let age_seconds = target.age().map(|d| d.as_secs());
+
+            Ok(serde_json::json!({
+                "rolled_back": true,
+                "project_id": project_id,
+                "previous_deployment": current,
+                "new_deployment": target,
+                "target_age_seconds": age_seconds,
+            }))
+        })
+    }
+
+    /// Get deployment logs/events implementation.
+    fn get_deployment_logs(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+
+        let since = Self::get_param_time(&params, "since")?;
+        let filter = EventFilter::from_params(&params)?;
+        let follow = Self::get_param_bool(&params, "follow", false);
+        let max_wait_secs = Self::get_param_i64(&params, "max_wait_secs").unwrap_or(600);
+        let poll_interval_secs = Self::get_param_i64(&params, "poll_interval_secs").unwrap_or(5);
+        if max_wait_secs <= 0 || poll_interval_secs <= 0 {
+            anyhow::bail!("max_wait_secs and poll_interval_secs must be positive");
+        }
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        // Events without a timestamp can't be placed relative to `since`, so keep them.
+        let after_since = move |event: &DeploymentEvent| match (since, event.created) {
+            (Some(since), Some(created)) => created >= since,
+            _ => true,
+        };
+
+        if !follow {
+            let events: Vec<DeploymentEvent> = self
+                .runtime
+                .block_on(async move {
+                    client
+                        .get_deployment_events(&deployment_id, team_id.as_deref())
+                        .await
+                })?
+                .into_iter()
+                .filter(after_since)
+                .collect();
+            let total = events.len();
+            let events = filter.apply(events);
+
+            return Ok(serde_json::json!({
+                "events": events,
+                "count": events.len(),
+                "total": total,
+            }));
+        }
+
+        let subject = format!("deployment {}", deployment_id);
+        self.runtime
+            .block_on(async move {
+                let start = std::time::Instant::now();
+                let max_wait = Duration::from_secs(max_wait_secs as u64);
+                let mut events: Vec<DeploymentEvent> = Vec::new();
+                // The events endpoint always returns the full log, so track how
+                // many matching events have been collected and skip those.
+                let mut seen = 0;
+
+                loop {
+                    // Check the state before fetching events so a terminal
+                    // state guarantees the final fetch has the whole log.
+                    let deployment = client
+                        .get_deployment(&deployment_id, team_id.as_deref())
+                        .await?;
+                    let batch: Vec<DeploymentEvent> = client
+                        .get_deployment_events(&deployment_id, team_id.as_deref())
+                        .await?
+                        .into_iter()
+                        .filter(after_since)
+                        .collect();
+                    events.extend(batch.into_iter().skip(seen));
+                    seen = events.len();
+
+                    let timed_out = !deployment.is_terminal() && start.elapsed() >= max_wait;
+                    if deployment.is_terminal() || timed_out {
+                        let total = events.len();
+                        let events = filter.apply(events);
+                        return Ok(serde_json::json!({
+                            "events": events,
+                            "count": events.len(),
+                            "total": total,
+                            "state": deployment.ready_state,
+                            "finished": deployment.is_terminal(),
+                            "timed_out": timed_out,
+                            "elapsed_secs": start.elapsed().as_secs(),
+                        }));
+                    }
+                    tokio::time::sleep(Duration::from_secs(poll_interval_secs as u64)).await;
+                }
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Build summary implementation: timings, cache use, exit status, and
+    /// warning/error counts for one deployment.
+    fn build_summary(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        let (deployment, events) = self
+            .runtime
+            .block_on(async move {
+                let deployment = client
+                    .get_deployment(&deployment_id, team_id.as_deref())
+                    .await?;
+                let events = client
+                    .get_deployment_events(&deployment.uid, team_id.as_deref())
+                    .await?;
+                anyhow::Ok((deployment, events))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(Self::summarize_build(&deployment, &events))
+    }
+
+    /// Derive build figures from a deployment and its events.
+    fn summarize_build(deployment: &Deployment, events: &[DeploymentEvent]) -> Value {
+        let mut warnings = 0;
+        let mut errors = 0;
+        let mut cache_restored = None;
+        let mut exit_code = None;
+        for event in events {
+            if let Some(code) = event
+                .payload
+                .as_ref()
+                .and_then(|p| p.get("exitCode"))
+                .and_then(|c| c.as_i64())
+            {
+                exit_code = Some(code);
+            }
+            let Some(message) = event.message() else {
+                continue;
+            };
+            if BUILD_WARNING.is_match(message) {
+                warnings += 1;
+            }
+            if BUILD_ERROR.is_match(message) {
+                errors += 1;
+            }
+            if message.contains("Restored build cache") {
+                cache_restored = Some(true);
+            } else if message.contains("build cache")
+                && (message.contains("not available") || message.contains("Skipping"))
+            {
+                cache_restored = Some(false);
+            }
+            if let Some(code) = BUILD_EXIT.captures(message).and_then(|c| c[1].parse().ok()) {
+                exit_code = Some(code);
+            }
+        }
+
+        serde_json::json!({
+            "deployment_id": deployment.uid,
+            "state": deployment.ready_state,
+            "target": deployment.target,
+            "framework": deployment
+                .project_settings
+                .as_ref()
+                .and_then(|s| s.framework.as_deref()),
+            "regions": deployment.regions,
+            "queued_ms": deployment.queue_duration().map(|d| d.as_millis() as u64),
+            "build_ms": deployment.build_duration().map(|d| d.as_millis() as u64),
+            "cache_restored": cache_restored,
+            "exit_code": exit_code,
+            "warnings": warnings,
+            "errors": errors,
+            "event_count": events.len(),
+        })
+    }
+
+    /// List functions implementation.
+    fn list_functions(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        let functions = self
+            .runtime
+            .block_on(async move {
+                client
+                    .list_functions(&deployment_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "functions": functions,
+            "count": functions.len(),
+        }))
+    }
+
+    /// Get deployment checks implementation.
+    fn get_deployment_checks(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        let checks = self
+            .runtime
+            .block_on(async move {
+                client
+                    .get_deployment_checks(&deployment_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "count": checks.len(),
+            "all_passed": checks.iter().all(DeploymentCheck::passed),
+            "any_blocking_failed": checks.iter().any(|c| c.blocking && c.failed()),
+            "pending": checks.iter().filter(|c| c.status != "completed").count(),
+            "checks": checks,
+        }))
+    }
+
+    /// Get a single deployment check implementation.
+    fn get_check(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let check_id = Self::get_param_str(&params, "check_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: check_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("check {} of deployment {}", check_id, deployment_id);
+        let check = self
+            .runtime
+            .block_on(async move {
+                client
+                    .get_check(&deployment_id, &check_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::to_value(check)?)
+    }
+
+    /// Rerun a deployment check implementation.
+    fn rerun_check(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let check_id = Self::get_param_str(&params, "check_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: check_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("check {} of deployment {}", check_id, deployment_id);
+        self.runtime
+            .block_on(async move {
+                client
+                    .rerequest_check(&deployment_id, &check_id, team_id.as_deref())
+                    .await?;
+
+                Ok(serde_json::json!({
+                    "rerequested": true,
+                    "deployment_id": deployment_id,
+                    "check_id": check_id,
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// List deployment files implementation.
+    fn list_deployment_files(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        let files = self
+            .runtime
+            .block_on(async move {
+                client
+                    .list_deployment_files(&deployment_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "files": files,
+            "file_count": files.iter().map(FileTree::file_count).sum::<usize>(),
+        }))
+    }
+
+    /// Get deployment file implementation.
+    ///
+    /// Accepts a `file_id` or a `path`, which is resolved by walking the file
+    /// tree. Contents beyond `max_bytes` are cut off and flagged as truncated.
+    fn get_deployment_file(&self, params: HashMap<String, Value>) -> Result<Value> {
+        use base64::Engine;
+
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let file_id = Self::get_param_str(&params, "file_id").map(|s| s.to_string());
+        let path = Self::get_param_str(&params, "path").map(|s| s.to_string());
+        if file_id.is_none() && path.is_none() {
+            anyhow::bail!("Missing required parameter: file_id or path");
+        }
+        let max_bytes = Self::get_param_i64(&params, "max_bytes").unwrap_or(DEFAULT_FILE_MAX_BYTES);
+        if max_bytes <= 0 {
+            anyhow::bail!("max_bytes must be positive (got {})", max_bytes);
+        }
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        let (file_id, encoded) = self
+            .runtime
+            .block_on(async move {
+                let file_id = match (file_id, path) {
+                    (Some(file_id), _) => file_id,
+                    (None, Some(path)) => {
+                        let files = client
+                            .list_deployment_files(&deployment_id, team_id.as_deref())
+                            .await?;
+                        let entry = FileTree::find(&files, &path).ok_or_else(|| {
+                            anyhow::anyhow!("No file at {:?} in deployment {}", path, deployment_id)
+                        })?;
+                        if entry.file_type == "directory" {
+                            anyhow::bail!("{:?} is a directory; use vercel.files to list it", path);
+                        }
+                        entry.uid.clone().ok_or_else(|| {
+                            anyhow::anyhow!("{:?} has no file ID and cannot be fetched", path)
+                        })?
+                    }
+                    (None, None) => unreachable!("checked above"),
+                };
+                let encoded = client
+                    .get_deployment_file(&deployment_id, &file_id, team_id.as_deref())
+                    .await?;
+                Ok((file_id, encoded))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        let mut bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded.trim())
+            .context("File contents were not valid base64")?;
+        let size = bytes.len();
+        let truncated = size > max_bytes as usize;
+        bytes.truncate(max_bytes as usize);
+        if truncated {
+            // Don't let the cut turn a text file into binary by splitting a
+            // multi-byte character.
+            if let Err(e) = std::str::from_utf8(&bytes) {
+                if e.error_len().is_none() {
+                    bytes.truncate(e.valid_up_to());
+                }
+            }
+        }
+
+        // Text goes back as-is; anything else stays base64 so it survives JSON.
+        let (encoding, content) = match String::from_utf8(bytes) {
+            Ok(text) => ("utf-8", text),
+            Err(e) => (
+                "base64",
+                base64::engine::general_purpose::STANDARD.encode(e.into_bytes()),
+            ),
+        };
+
+        Ok(serde_json::json!({
+            "file_id": file_id,
+            "path": Self::get_param_str(&params, "path"),
+            "size": size,
+            "truncated": truncated,
+            "encoding": encoding,
+            "content": content,
+        }))
+    }
+
+    /// Stream deployment logs implementation.
+    ///
+    /// Follows the event stream until Vercel closes it (build finished) or
+    /// `timeout_ms` elapses, then returns everything collected in one response.
+    fn stream_logs(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let timeout_ms = Self::get_param_i64(&params, "timeout_ms").unwrap_or(600_000);
+        if timeout_ms <= 0 {
+            anyhow::bail!("timeout_ms must be positive");
+        }
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        self.runtime
+            .block_on(async move {
+                let deadline =
+                    tokio::time::Instant::now() + Duration::from_millis(timeout_ms as u64);
+                let stream = client
+                    .stream_deployment_events(&deployment_id, team_id.as_deref())
+                    .await?;
+                let mut stream = std::pin::pin!(stream);
+
+                let mut events = Vec::new();
+                let mut complete = false;
+                let mut error = None;
+                while let Ok(next) = tokio::time::timeout_at(deadline, stream.next()).await {
+                    match next {
+                        Some(Ok(event)) => events.push(event),
+                        Some(Err(e)) if events.is_empty() => return Err(e),
+                        Some(Err(e)) => {
+                            error = Some(format!("{:#}", e));
+                            break;
+                        }
+                        None => {
+                            complete = true;
+                            break;
+                        }
+                    }
+                }
+
+                Ok(serde_json::json!({
+                    "deployment_id": deployment_id,
+                    "events": events,
+                    "count": events.len(),
+                    "complete": complete,
+                    "timed_out": !complete && error.is_none(),
+                    "error": error,
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Get user info implementation.
+    fn get_user(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let include_teams = Self::get_param_bool(&params, "include_teams", false);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let user = client.get_user().await?;
+            let mut result = serde_json::json!({ "user": user });
+
+            if include_teams {
+                let teams = client.list_teams(Some(100), None).await?;
+                result["teams"] = serde_json::to_value(teams.items)?;
+            }
+
+            Ok(result)
+        })
+    }
+
+    /// List teams implementation.
+    fn list_teams(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let limit = Self::get_param_i32(&params, "limit", 20);
+        let until = Self::get_param_i64(&params, "until");
+        let client = self.client.clone();
+
+        let page = self
+            .runtime
+            .block_on(async move { client.list_teams(Some(limit), until).await })?;
+
+        Ok(serde_json::json!({
+            "teams": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.pagination.and_then(|p| p.next),
+        }))
+    }
+
+    /// Get team implementation.
+    fn get_team(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let team_id = Self::get_param_str(&params, "team_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: team_id"))?
+            .to_string();
+
+        let client = self.client.clone();
+
+        let subject = format!("team {}", team_id);
+        let team = self
+            .runtime
+            .block_on(async move { client.get_team(&team_id).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::to_value(team)?)
+    }
+
+    /// Team for team-level methods: the `team_id` param, else the daemon's
+    /// default team.
+    fn require_team_id(&self, params: &HashMap<String, Value>) -> Result<String> {
+        Self::get_team_id(params)
+            .or_else(|| self.client.default_team_id().map(str::to_string))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Missing required parameter: team_id (or start the daemon with --team)"
+                )
+            })
+    }
+
+    /// Helper to get a team role parameter, upper-cased and validated.
+    fn get_param_role(params: &HashMap<String, Value>) -> Result<String> {
+        let role = Self::get_param_str(params, "role")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: role"))?
+            .to_ascii_uppercase();
+        if !TEAM_ROLES.contains(&role.as_str()) {
+            anyhow::bail!(
+                "Invalid role: {} (expected one of {})",
+                role,
+                TEAM_ROLES.join(", ")
+            );
+        }
+        Ok(role)
+    }
+
+    /// List team members implementation.
+    fn list_team_members(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let team_id = self.require_team_id(&params)?;
+        let limit = Self::get_param_i32(&params, "limit", 20);
+        let until =
+            Self::get_param_i64(&params, "until").or_else(|| Self::get_param_i64(&params, "next"));
+        let client = self.client.clone();
+
+        let subject = format!("team {}", team_id);
+        if Self::get_param_bool(&params, "all", false) {
+            let max_items = Self::get_max_items(&params)?;
+            let collected = self.runtime.block_on(async move {
+                client
+                    .list_team_members_all(&team_id, max_items, until)
+                    .await
+            });
+            return Self::collected_json("members", collected, max_items);
+        }
+
+        let page = self
+            .runtime
+            .block_on(async move {
+                client
+                    .list_team_members_page(&team_id, Some(limit), until)
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "members": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.pagination.and_then(|p| p.next),
+        }))
+    }
+
+    /// Invite team member implementation.
+    fn invite_team_member(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let team_id = self.require_team_id(&params)?;
+        let email = Self::get_param_str(&params, "email")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: email"))?
+            .to_string();
+        if !email.contains('@') {
+            anyhow::bail!("Invalid email: {}", email);
+        }
+        let role = Self::get_param_role(&params)?;
+        let client = self.client.clone();
+
+        let subject = format!("team {}", team_id);
+        let invited = self
+            .runtime
+            .block_on(async move { client.invite_team_member(&team_id, &email, &role).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(invited)
+    }
+
+    /// Change team member role implementation.
+    ///
+    /// Refuses to demote the team's last owner.
+    fn update_team_member_role(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let team_id = self.require_team_id(&params)?;
+        let uid = Self::get_param_str(&params, "uid")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: uid"))?
+            .to_string();
+        let role = Self::get_param_role(&params)?;
+        let client = self.client.clone();
+
+        let subject = format!("team {}", team_id);
+        self.runtime
+            .block_on(async move {
+                if role != "OWNER" {
+                    Self::check_not_last_owner(&client, &team_id, &uid, "demote").await?;
+                }
+                client
+                    .update_team_member_role(&team_id, &uid, &role)
+                    .await?;
+                Ok(serde_json::json!({
+                    "updated": true,
+                    "uid": uid,
+                    "role": role,
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Remove team member implementation.
+    ///
+    /// Refuses to remove the team's last owner.
+    fn remove_team_member(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let team_id = self.require_team_id(&params)?;
+        let uid = Self::get_param_str(&params, "uid")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: uid"))?
+            .to_string();
+        let client = self.client.clone();
+
+        let subject = format!("team {}", team_id);
+        self.runtime
+            .block_on(async move {
+                Self::check_not_last_owner(&client, &team_id, &uid, "remove").await?;
+                client.remove_team_member(&team_id, &uid).await?;
+                Ok(serde_json::json!({
+                    "removed": true,
+                    "uid": uid,
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Fail if `uid` is the only confirmed owner of the team.
+    async fn check_not_last_owner(
+        client: &CachingVercelClient,
+        team_id: &str,
+        uid: &str,
+        action: &str,
+    ) -> Result<()> {
+        let members = client
+            .list_team_members_all(team_id, usize::MAX, None)
+            .await;
+        if let Some(error) = members.error {
+            anyhow::bail!("Failed to list team members: {}", error);
+        }
+
+        let owners: Vec<&str> = members
+            .items
+            .iter()
+            .filter(|m| m.is_owner() && m.confirmed != Some(false))
+            .map(|m| m.uid.as_str())
+            .collect();
+        if owners == [uid] {
+            anyhow::bail!(
+                "Refusing to {} {}: they are the last owner of team {}",
+                action,
+                uid,
+                team_id
+            );
+        }
+        Ok(())
+    }
+
+    /// List env vars implementation.
+    ///
+    /// Values of `encrypted` and `sensitive` vars are masked unless `reveal` is set.
+    fn list_env_vars(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let target = Self::get_param_str(&params, "target").map(|s| s.to_string());
+        let key_prefix = Self::get_param_str(&params, "key_prefix").map(|s| s.to_string());
+        let env_type = Self::get_param_str(&params, "type").map(|s| s.to_string());
+        let git_branch = Self::get_param_str(&params, "git_branch").map(|s| s.to_string());
+        let reveal = Self::get_param_bool(&params, "reveal", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let envs = self.runtime.block_on(async move {
+            client
+                .list_env_vars(&project_id, target.as_deref(), reveal, team_id.as_deref())
+                .await
+        })?;
+
+        // For a branch, keep what its preview deployments receive: vars scoped
+        // to that branch, plus unscoped preview vars it doesn't override.
+        let envs = match git_branch.as_deref() {
+            Some(branch) => {
+                let overridden: Vec<String> = envs
+                    .iter()
+                    .filter(|e| e.git_branch.as_deref() == Some(branch))
+                    .map(|e| e.key.clone())
+                    .collect();
+                envs.into_iter()
+                    .filter(|e| e.target.iter().any(|t| t == "preview"))
+                    .filter(|e| match e.git_branch.as_deref() {
+                        Some(b) => b == branch,
+                        None => !overridden.contains(&e.key),
+                    })
+                    .collect()
+            }
+            None => envs,
+        };
+
+        let envs: Vec<_> = envs
+            .into_iter()
+            .filter(|e| key_prefix.as_deref().is_none_or(|p| e.key.starts_with(p)))
+            .filter(|e| env_type.is_none() || e.env_type == env_type)
+            .map(|mut e| {
+                if !reveal && e.is_secret() {
+                    e.value = None;
+                }
+                e
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "env_vars": envs,
+            "count": envs.len(),
+        }))
+    }
+
+    /// Set env var implementation.
+    fn set_env_var(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let key = Self::get_param_str(&params, "key")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: key"))?
+            .to_string();
+        let value = Self::get_param_str(&params, "value")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: value"))?
+            .to_string();
+
+        let target = Self::parse_targets(params.get("target"))?;
+        let git_branch = Self::get_param_str(&params, "git_branch");
+        Self::check_git_branch(git_branch, &target)?;
+
+        let var = EnvVarInput {
+            key,
+            value,
+            target,
+            env_type: Self::get_param_str(&params, "type")
+                .unwrap_or("encrypted")
+                .to_string(),
+            git_branch: git_branch.map(|s| s.to_string()),
+        };
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let result = self.runtime.block_on(async move {
+            client
+                .set_env_var(&project_id, &var, team_id.as_deref())
+                .await
+        })?;
+
+        Ok(result)
+    }
+
+    /// Bulk set env vars implementation.
+    ///
+    /// Every item is validated before anything is sent; the response reports
+    /// per-item results so a partial failure isn't mistaken for a total one.
+    /// Existing keys are updated in place unless `upsert` is false.
+    fn bulk_set_env_vars(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let items = params
+            .get("vars")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: vars (array)"))?;
+        if items.is_empty() {
+            anyhow::bail!("vars must contain at least one item");
+        }
+        let upsert = Self::get_param_bool(&params, "upsert", true);
+
+        let vars = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                Self::parse_new_env_var(item).map_err(|e| anyhow::anyhow!("vars[{}]: {}", i, e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let result = self.runtime.block_on(async move {
+            client
+                .bulk_set_env_vars(&project_id, &vars, upsert, team_id.as_deref())
+                .await
+        })?;
+
+        let created: Vec<_> = result
+            .created
+            .into_iter()
+            .map(|mut e| {
+                if e.is_secret() {
+                    e.value = None;
+                }
+                e
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "ok": result.failed.is_empty(),
+            "created_count": created.len(),
+            "failed_count": result.failed.len(),
+            "created": created,
+            "failed": result.failed,
+        }))
+    }
+
+    /// Parse an env `target` given as a string or array; defaults to all environments.
+    fn parse_targets(value: Option<&Value>) -> Result<Vec<String>> {
+        match value {
+            None | Some(Value::Null) => Ok(vec![
+                "production".to_string(),
+                "preview".to_string(),
+                "development".to_string(),
+            ]),
+            Some(Value::String(t)) => Ok(vec![t.clone()]),
+            Some(Value::Array(ts)) => ts
+                .iter()
+                .map(|t| {
+                    t.as_str()
+                        .map(|s| s.to_string())
+                        .ok_or_else(|| anyhow::anyhow!("target entries must be strings"))
+                })
+                .collect(),
+            Some(_) => anyhow::bail!("target must be a string or array"),
+        }
+    }
+
+    /// Parse one `{key, value, target?, type?, git_branch?}` item of `vars`.
+    fn parse_new_env_var(item: &Value) -> Result<EnvVarInput> {
+        let field = |name: &str| item.get(name).and_then(|v| v.as_str());
+
+        let key = field("key")
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("missing key"))?;
+        let value = field("value").ok_or_else(|| anyhow::anyhow!("missing value for {}", key))?;
+        let target = Self::parse_targets(item.get("target"))?;
+        let git_branch = field("git_branch");
+        Self::check_git_branch(git_branch, &target)?;
+
+        Ok(EnvVarInput {
+            key: key.to_string(),
+            value: value.to_string(),
+            target,
+            env_type: field("type").unwrap_or("encrypted").to_string(),
+            git_branch: git_branch.map(|s| s.to_string()),
+        })
+    }
+
+    /// Vercel only accepts `gitBranch` on vars that target `preview`.
+    fn check_git_branch(git_branch: Option<&str>, target: &[String]) -> Result<()> {
+        match git_branch {
+            Some(branch) if !target.iter().any(|t| t == "preview") => anyhow::bail!(
+                "git_branch {:?} requires a target that includes \"preview\"",
+                branch
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Import env vars from dotenv content implementation.
+    ///
+    /// Keys missing from the requested targets are created; keys whose value
+    /// differs are only updated when `overwrite` is true, otherwise skipped.
+    /// Sensitive vars can't be read back, so they count as changed.
+    fn env_import(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let content = match (
+            Self::get_param_str(&params, "content"),
+            Self::get_param_str(&params, "path"),
+        ) {
+            (Some(content), _) => content.to_string(),
+            (None, Some(path)) => {
+                let path = shellexpand::tilde(path).to_string();
+                std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read env file: {}", path))?
+            }
+            (None, None) => anyhow::bail!("Missing required parameter: path or content"),
+        };
+        let targets = Self::parse_targets(params.get("target"))?;
+        let overwrite = Self::get_param_bool(&params, "overwrite", false);
+        let env_type = Self::get_param_str(&params, "type")
+            .unwrap_or("encrypted")
+            .to_string();
+
+        // Later assignments of the same key win, as when the file is sourced.
+        let mut entries: Vec<(String, String)> = Vec::new();
+        for (key, value) in dotenv::parse(&content)? {
+            entries.retain(|(k, _)| *k != key);
+            entries.push((key, value));
+        }
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let existing = client
+                .list_env_vars(&project_id, None, true, team_id.as_deref())
+                .await?;
+
+            let mut to_create = Vec::new();
+            let mut to_update = Vec::new();
+            let mut skipped = Vec::new();
+            let mut unchanged = Vec::new();
+
+            for (key, value) in &entries {
+                let matching: Vec<_> = existing
+                    .iter()
+                    .filter(|e| e.key == *key && e.target.iter().any(|t| targets.contains(t)))
+                    .collect();
+
+                let missing: Vec<String> = targets
+                    .iter()
+                    .filter(|t| !matching.iter().any(|e| e.target.contains(t)))
+                    .cloned()
+                    .collect();
+                if !missing.is_empty() {
+                    to_create.push(EnvVarInput {
+                        key: key.clone(),
+                        value: value.clone(),
+                        target: missing,
+                        env_type: env_type.clone(),
+                        git_branch: None,
+                    });
+                }
+
+                let changed: Vec<_> = matching
+                    .iter()
+                    .filter(|e| e.value.as_deref() != Some(value.as_str()))
+                    .collect();
+                if matching.is_empty() {
+                    continue;
+                } else if changed.is_empty() {
+                    unchanged.push(key.clone());
+                } else if overwrite {
+                    to_update.extend(changed.iter().map(|e| (key.clone(), e.id.clone(), value)));
+                } else {
+                    skipped.push(key.clone());
+                }
+            }
+
+            let mut created = Vec::new();
+            let mut failed = Vec::new();
+            if !to_create.is_empty() {
+                let result = client
+                    .bulk_set_env_vars(&project_id, &to_create, false, team_id.as_deref())
+                    .await?;
+                for var in &to_create {
+                    let failure = result.failed.iter().find(|f| {
+                        f["error"]["key"] == var.key.as_str() || f["key"] == var.key.as_str()
+                    });
+                    match failure {
+                        Some(f) => failed.push(serde_json::json!({
+                            "key": var.key,
+                            "error": f["error"]["message"].as_str().unwrap_or("create failed"),
+                        })),
+                        None if !created.contains(&var.key) => created.push(var.key.clone()),
+                        None => {}
+                    }
+                }
+            }
+
+            let mut updated = Vec::new();
+            for (key, env_id, value) in to_update {
+                match client
+                    .update_env_var(&project_id, &env_id, value, None, team_id.as_deref())
+                    .await
+                {
+                    Ok(_) if !updated.contains(&key) => updated.push(key),
+                    Ok(_) => {}
+                    Err(e) => failed.push(serde_json::json!({
+                        "key": key,
+                        "error": e.to_string(),
+                    })),
+                }
+            }
+
+            Ok(serde_json::json!({
+                "created": created,
+                "updated": updated,
+                "skipped": skipped,
+                "unchanged": unchanged,
+                "failed": failed,
+            }))
+        })
+    }
+
+    /// Export env vars as a dotenv document implementation.
+    ///
+    /// Sensitive vars can't be read back, so they are written as commented-out
+    /// placeholders. With `path`, the file is written with 0600 permissions.
+    fn env_export(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let target = Self::get_param_str(&params, "target").map(|s| s.to_string());
+        let path = Self::get_param_str(&params, "path").map(|p| shellexpand::tilde(p).to_string());
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let envs = self.runtime.block_on(async move {
+            client
+                .list_env_vars(&project_id, target.as_deref(), true, team_id.as_deref())
+                .await
+        })?;
+
+        let mut content = String::new();
+        let mut exported = Vec::new();
+        let mut sensitive = Vec::new();
+        for env in &envs {
+            // Without a target filter a key can repeat once per environment; keep the first.
+            if exported.contains(&env.key) || sensitive.contains(&env.key) {
+                continue;
+            }
+            match env.value.as_deref() {
+                Some(value) if env.env_type.as_deref() != Some("sensitive") => {
+                    content.push_str(&dotenv::render_line(&env.key, value));
+                    exported.push(env.key.clone());
+                }
+                _ => {
+                    content.push_str(&format!("# {}=<sensitive>", env.key));
+                    sensitive.push(env.key.clone());
+                }
+            }
+            content.push('\n');
+        }
+
+        let mut result = serde_json::json!({
+            "count": exported.len(),
+            "keys": exported,
+            "sensitive": sensitive,
+        });
+        match path {
+            Some(path) => {
+                Self::write_private_file(&path, &content)
+                    .with_context(|| format!("Failed to write env file: {}", path))?;
+                result["path"] = Value::String(path);
+            }
+            None => result["content"] = Value::String(content),
+        }
+        Ok(result)
+    }
+
+    /// Write `content` to `path`, readable only by the owner.
+    fn write_private_file(path: &str, content: &str) -> std::io::Result<()> {
+        use std::io::Write;
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        // `mode` only applies on creation; tighten an existing file too.
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        file.write_all(content.as_bytes())
+    }
+
+    /// Copy env vars between projects implementation.
+    ///
+    /// Sensitive vars can't be read from the source and are reported rather
+    /// than copied. Keys already set on the destination for an overlapping
+    /// target are skipped unless `overwrite` is true. `VERCEL_*` system vars
+    /// are never copied, and `dry_run` reports the plan without writing.
+    fn copy_env_vars(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let source_id = Self::get_param_str(&params, "source_project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: source_project_id"))?
+            .to_string();
+        let dest_id = Self::get_param_str(&params, "dest_project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: dest_project_id"))?
+            .to_string();
+        let target = Self::get_param_str(&params, "target").map(|s| s.to_string());
+        let key_prefix = Self::get_param_str(&params, "key_prefix").map(|s| s.to_string());
+        let overwrite = Self::get_param_bool(&params, "overwrite", false);
+        let dry_run = Self::get_param_bool(&params, "dry_run", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let team_id = team_id.as_deref();
+            let source = client
+                .get_project(&source_id, team_id)
+                .await
+                .map_err(|e| Self::explain_api_error(e, &format!("project {}", source_id)))?;
+            let dest = client
+                .get_project(&dest_id, team_id)
+                .await
+                .map_err(|e| Self::explain_api_error(e, &format!("project {}", dest_id)))?;
+            if source.id == dest.id {
+                anyhow::bail!(
+                    "Refusing to copy env vars from project {} onto itself",
+                    source.name
+                );
+            }
+
+            let source_envs = client
+                .list_env_vars(&source.id, target.as_deref(), true, team_id)
+                .await?;
+            let dest_envs = client.list_env_vars(&dest.id, None, false, team_id).await?;
+
+            let mut to_copy = Vec::new();
+            let mut overwritten = Vec::new();
+            let mut skipped = Vec::new();
+            let mut sensitive = Vec::new();
+            for env in source_envs {
+                if env.key.starts_with("VERCEL_")
+                    || key_prefix
+                        .as_deref()
+                        .is_some_and(|p| !env.key.starts_with(p))
+                {
+                    continue;
+                }
+                let value = match env.value {
+                    Some(value) if env.env_type.as_deref() != Some("sensitive") => value,
+                    _ => {
+                        tracing::warn!(
+                            key = %env.key,
+                            project = %source.id,
+                            "Skipping sensitive env var: value cannot be read"
+                        );
+                        sensitive.push(env.key);
+                        continue;
+                    }
+                };
+
+                // With a target filter, only copy into that environment.
+                let targets = match &target {
+                    Some(t) => vec![t.clone()],
+                    None => env.target,
+                };
+                let exists = dest_envs
+                    .iter()
+                    .any(|d| d.key == env.key && d.target.iter().any(|t| targets.contains(t)));
+                if exists && !overwrite {
+                    skipped.push(env.key);
+                    continue;
+                }
+                if exists {
+                    overwritten.push(env.key.clone());
+                }
+
+                to_copy.push(EnvVarInput {
+                    key: env.key,
+                    value,
+                    target: targets,
+                    env_type: env.env_type.unwrap_or_else(|| "encrypted".to_string()),
+                    git_branch: env.git_branch,
+                });
+            }
+
+            if dry_run {
+                let planned: Vec<&str> = to_copy.iter().map(|v| v.key.as_str()).collect();
+                return Ok(serde_json::json!({
+                    "dry_run": true,
+                    "source_project_id": source.id,
+                    "dest_project_id": dest.id,
+                    "would_copy": planned,
+                    "would_overwrite": overwritten,
+                    "skipped": skipped,
+                    "sensitive": sensitive,
+                }));
+            }
+
+            let mut copied = Vec::new();
+            let mut failed = Vec::new();
+            if !to_copy.is_empty() {
+                let result = client
+                    .bulk_set_env_vars(&dest.id, &to_copy, overwrite, team_id)
+                    .await?;
+                failed = result.failed;
+                copied = result.created.into_iter().map(|e| e.key).collect();
+            }
+
+            Ok(serde_json::json!({
+                "source_project_id": source.id,
+                "dest_project_id": dest.id,
+                "copied": copied,
+                "overwritten": overwritten,
+                "skipped": skipped,
+                "sensitive": sensitive,
+                "failed": failed,
+            }))
+        })
+    }
+
+    /// Diff env vars implementation.
+    ///
+    /// Compares two targets of one project (`project_id`, `target_a`,
+    /// `target_b`) or two projects (`project_a`, `project_b`, optional
+    /// `target`). Branch-scoped vars are reported separately under
+    /// `branch_scoped`, and values are masked unless `reveal` is true.
+    fn env_diff(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let reveal = Self::get_param_bool(&params, "reveal", false);
+        let str_param = |key: &str| Self::get_param_str(&params, key).map(|s| s.to_string());
+
+        // Each side is (project, target filter); `per_target` keys entries by
+        // target when a project diff isn't narrowed to one target.
+        let (side_a, side_b, per_target) = match (
+            str_param("project_id"),
+            str_param("project_a"),
+            str_param("project_b"),
+        ) {
+            (Some(project), None, None) => {
+                let target_a = str_param("target_a")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required parameter: target_a"))?;
+                let target_b = str_param("target_b")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required parameter: target_b"))?;
+                (
+                    (project.clone(), Some(target_a)),
+                    (project, Some(target_b)),
+                    false,
+                )
+            }
+            (None, Some(project_a), Some(project_b)) => {
+                let target = str_param("target");
+                let per_target = target.is_none();
+                ((project_a, target.clone()), (project_b, target), per_target)
+            }
+            _ => anyhow::bail!(
+                "Pass either project_id with target_a and target_b, or project_a and project_b"
+            ),
+        };
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let (envs_a, envs_b) = self.runtime.block_on(async move {
+            let team_id = team_id.as_deref();
+            let a = client
+                .list_env_vars(&side_a.0, side_a.1.as_deref(), true, team_id)
+                .await?;
+            let b = client
+                .list_env_vars(&side_b.0, side_b.1.as_deref(), true, team_id)
+                .await?;
+            Ok::<_, anyhow::Error>((a, b))
+        })?;
+
+        // (git_branch, target, key) -> value; `None` values can't be compared.
+        type Entries = BTreeMap<(Option<String>, Option<String>, String), Option<String>>;
+        let entries = |envs: Vec<EnvVar>| -> Entries {
+            let mut map = Entries::new();
+            for env in envs {
+                let value = if env.env_type.as_deref() == Some("sensitive") {
+                    None
+                } else {
+                    env.value
+                };
+                let targets: Vec<Option<String>> = if per_target {
+                    env.target.into_iter().map(Some).collect()
+                } else {
+                    vec![None]
+                };
+                for target in targets {
+                    map.insert(
+                        (env.git_branch.clone(), target, env.key.clone()),
+                        value.clone(),
+                    );
+                }
+            }
+            map
+        };
+        let a = entries(envs_a);
+        let b = entries(envs_b);
+
+        let describe = |(branch, target, key): &(Option<String>, Option<String>, String)| {
+            let mut entry = serde_json::json!({ "key": key });
+            if let Some(target) = target {
+                entry["target"] = Value::from(target.as_str());
+            }
+            if let Some(branch) = branch {
+                entry["git_branch"] = Value::from(branch.as_str());
+            }
+            entry
+        };
+
+        #[derive(Default, serde::Serialize)]
+        struct Diff {
+            only_in_a: Vec<Value>,
+            only_in_b: Vec<Value>,
+            different_values: Vec<Value>,
+            not_comparable: Vec<Value>,
+        }
+
+        let mut shared = Diff::default();
+        let mut branch_scoped = Diff::default();
+        for (id, value_a) in &a {
+            let group = if id.0.is_some() {
+                &mut branch_scoped
+            } else {
+                &mut shared
+            };
+            match b.get(id) {
+                None => group.only_in_a.push(describe(id)),
+                Some(value_b) if value_a.is_none() || value_b.is_none() => {
+                    group.not_comparable.push(describe(id))
+                }
+                Some(value_b) if value_a != value_b => {
+                    let mut entry = describe(id);
+                    if reveal {
+                        entry["a"] = Value::from(value_a.clone());
+                        entry["b"] = Value::from(value_b.clone());
+                    }
+                    group.different_values.push(entry);
+                }
+                Some(_) => {}
+            }
+        }
+        for id in b.keys().filter(|id| !a.contains_key(*id)) {
+            let group = if id.0.is_some() {
+                &mut branch_scoped
+            } else {
+                &mut shared
+            };
+            group.only_in_b.push(describe(id));
+        }
+
+        let mut result = serde_json::to_value(shared)?;
+        result["branch_scoped"] = serde_json::to_value(branch_scoped)?;
+        Ok(result)
+    }
+
+    /// Resolve an env var id from either `env_id` or a `key` (+ optional `target`).
+    async fn resolve_env_id(
+        client: &VercelClient,
+        project_id: &str,
+        env_id: Option<String>,
+        key: Option<&str>,
+        target: Option<&str>,
+        team_id: Option<&str>,
+    ) -> Result<String> {
+        match (env_id, key) {
+            (Some(id), _) => Ok(id),
+            (None, Some(key)) => {
+                let listing = client
+                    .list_env_vars(project_id, target, false, team_id)
+                    .await?;
+                let matches: Vec<_> = listing.iter().filter(|e| e.key == key).collect();
+
+                match matches.as_slice() {
+                    [] => anyhow::bail!("No environment variable found with key: {}", key),
+                    [env] => Ok(env.id.clone()),
+                    _ => {
+                        let ids: Vec<&str> = matches.iter().map(|e| e.id.as_str()).collect();
+                        anyhow::bail!(
+                            "Key {} matches {} environment variables ({}); pass env_id or a narrower target",
+                            key,
+                            matches.len(),
+                            ids.join(", ")
+                        );
+                    }
+                }
+            }
+            (None, None) => anyhow::bail!("Missing required parameter: env_id or key"),
+        }
+    }
+
+    /// Get env var implementation.
+    ///
+    /// Sensitive vars never expose their value; they come back as
+    /// `value: null, sensitive: true`.
+    fn get_env_var(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let env_id = Self::get_param_str(&params, "env_id").map(|s| s.to_string());
+        let key = Self::get_param_str(&params, "key").map(|s| s.to_string());
+        let target = Self::get_param_str(&params, "target").map(|s| s.to_string());
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let env_id = Self::resolve_env_id(
+                &client,
+                &project_id,
+                env_id,
+                key.as_deref(),
+                target.as_deref(),
+                team_id.as_deref(),
+            )
+            .await?;
+
+            let mut env = client
+                .get_env_var(&project_id, &env_id, team_id.as_deref())
+                .await
+                .map_err(|e| {
+                    Self::explain_api_error(e, &format!("environment variable {}", env_id))
+                })?;
+
+            let sensitive = env.env_type.as_deref() == Some("sensitive");
+            if sensitive {
+                env.value = None;
+            }
+
+            let mut result = serde_json::to_value(env)?;
+            result["sensitive"] = Value::Bool(sensitive);
+            Ok(result)
+        })
+    }
+
+    /// Delete env var implementation.
+    ///
+    /// Accepts either `env_id` directly, or `key` (plus optional `target`) which
+    /// is resolved to an id via `list_env_vars`.
+    fn delete_env_var(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let env_id = Self::get_param_str(&params, "env_id").map(|s| s.to_string());
+        let key = Self::get_param_str(&params, "key").map(|s| s.to_string());
+        let target = Self::get_param_str(&params, "target").map(|s| s.to_string());
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let env_id = Self::resolve_env_id(
+                &client,
+                &project_id,
+                env_id,
+                key.as_deref(),
+                target.as_deref(),
+                team_id.as_deref(),
+            )
+            .await?;
+
+            let deleted = match client
+                .delete_env_var(&project_id, &env_id, team_id.as_deref())
+                .await
+            {
+                Ok(deleted) => deleted,
+                Err(e) => {
+                    let not_found = e
+                        .downcast_ref::<VercelApiError>()
+                        .is_some_and(VercelApiError::is_not_found);
+                    if !not_found {
+                        return Err(e);
+                    }
+
+                    // A common mistake is passing the variable name as env_id.
+                    // The original error stands if the listing fails too.
+                    let is_key = client
+                        .list_env_vars(&project_id, None, false, team_id.as_deref())
+                        .await
+                        .is_ok_and(|listing| listing.iter().any(|v| v.key == env_id));
+                    if is_key {
+                        anyhow::bail!(
+                            "env_id must be the UUID from vercel.env_vars, not the variable name"
+                        );
+                    }
+                    return Err(e);
+                }
+            };
+
+            Ok(serde_json::json!({
+                "deleted": true,
+                "id": env_id,
+                "key": key.map(Value::from).unwrap_or_else(|| deleted["key"].clone()),
+            }))
+        })
+    }
+
+    /// List domains implementation.
+    fn list_domains(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let result = self
+            .runtime
+            .block_on(async move { client.list_domains(&project_id, team_id.as_deref()).await })?;
+
+        Ok(result)
+    }
+
+    /// Add domain implementation.
+    fn add_domain(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let domain = Self::get_param_str(&params, "domain")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: domain"))?
+            .to_string();
+        let redirect = Self::get_param_str(&params, "redirect").map(|s| s.to_string());
+        let redirect_status_code = Self::get_param_i64(&params, "redirect_status_code");
+        let git_branch = Self::get_param_str(&params, "git_branch").map(|s| s.to_string());
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let result = self.runtime.block_on(async move {
+            client
+                .add_domain(
+                    &project_id,
+                    &domain,
+                    redirect.as_deref(),
+                    redirect_status_code,
+                    git_branch.as_deref(),
+                    team_id.as_deref(),
+                )
+                .await
+        })?;
+
+        // Unverified domains carry the TXT records that still need to be set.
+        Ok(serde_json::json!({
+            "verified": result["verified"].as_bool().unwrap_or(false),
+            "verification": result.get("verification").cloned().unwrap_or_else(|| serde_json::json!([])),
+            "domain": result,
+        }))
+    }
+
+    /// Move domain implementation.
+    ///
+    /// Adds the domain to the destination first (copying the source's
+    /// redirect and git branch), checks it is verified there, then removes it
+    /// from the source. A failed check or removal rolls the add back; `force`
+    /// moves an attached but unverified domain anyway. Every step is reported
+    /// so a partial move is visible.
+    fn move_domain(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let domain = Self::get_param_str(&params, "domain")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: domain"))?
+            .to_string();
+        let from = Self::get_param_str(&params, "from_project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: from_project_id"))?
+            .to_string();
+        let to = Self::get_param_str(&params, "to_project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: to_project_id"))?
+            .to_string();
+        if from == to {
+            anyhow::bail!("from_project_id and to_project_id are the same project");
+        }
+        let force = Self::get_param_bool(&params, "force", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let team_id = team_id.as_deref();
+            let source = client
+                .get_project_domain(&from, &domain, team_id)
+                .await
+                .map_err(|e| {
+                    Self::explain_api_error(e, &format!("domain {} on {}", domain, from))
+                })?;
+
+            let mut steps = Vec::new();
+            let step = |name: &str, project: &str, outcome: &Result<Value>| {
+                let mut step = serde_json::json!({
+                    "step": name,
+                    "project_id": project,
+                    "ok": outcome.is_ok(),
+                });
+                if let Err(e) = outcome {
+                    step["error"] = Value::String(format!("{:#}", e));
+                }
+                step
+            };
+            let finish = |moved: bool, steps: Vec<Value>| {
+                serde_json::json!({
+                    "domain": domain,
+                    "from_project_id": from,
+                    "to_project_id": to,
+                    "moved": moved,
+                    "steps": steps,
+                })
+            };
+
+            let added = client
+                .add_domain(
+                    &to,
+                    &domain,
+                    source["redirect"].as_str(),
+                    source["redirectStatusCode"].as_i64(),
+                    source["gitBranch"].as_str(),
+                    team_id,
+                )
+                .await;
+            steps.push(step("add", &to, &added));
+            if added.is_err() {
+                return Ok(finish(false, steps));
+            }
+
+            // An unverified domain serves nothing on the destination, so the
+            // source keeps it unless the caller forces the move.
+            let attached = client.get_project_domain(&to, &domain, team_id).await;
+            let mut check = step("verify", &to, &attached);
+            let verified = attached
+                .as_ref()
+                .is_ok_and(|record| record["verified"].as_bool().unwrap_or(false));
+            if attached.is_ok() {
+                check["verified"] = Value::Bool(verified);
+                if !verified && !force {
+                    check["ok"] = Value::Bool(false);
+                    check["error"] = Value::String(format!(
+                        "{} is not verified on {} (pass force: true to move it anyway)",
+                        domain, to
+                    ));
+                }
+            }
+            steps.push(check);
+
+            let removed = match attached {
+                Ok(_) if verified || force => {
+                    let removed = client.remove_domain(&from, &domain, team_id).await;
+                    steps.push(step("remove", &from, &removed));
+                    removed.is_ok()
+                }
+                _ => false,
+            };
+            if !removed {
+                let rollback = client.remove_domain(&to, &domain, team_id).await;
+                steps.push(step("rollback", &to, &rollback));
+            }
+
+            Ok(finish(removed, steps))
+        })
+    }
+
+    /// Remove domain implementation.
+    ///
+    /// Refuses to remove a domain serving production (not a redirect, not
+    /// tied to a git branch, and assigned to the project's production
+    /// deployment) unless `force: true` is passed. Without a production
+    /// target on the project record, a non-redirect apex domain counts.
+    fn remove_domain(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let domain = Self::get_param_str(&params, "domain")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: domain"))?
+            .to_string();
+        let force = Self::get_param_bool(&params, "force", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let listing = client.list_domains(&project_id, team_id.as_deref()).await?;
+            let domains = listing["domains"].as_array().cloned().unwrap_or_default();
+            let project = client.get_project(&project_id, team_id.as_deref()).await?;
+
+            let entry = domains
+                .iter()
+                .find(|d| d["name"] == domain.as_str())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Domain {} is not attached to project {}", domain, project_id)
+                })?;
+
+            let is_redirect = entry["redirect"].as_str().is_some();
+            let is_branch = entry["gitBranch"].as_str().is_some();
+            let on_production = match project.production_domains() {
+                Some(domains) => domains.contains(&domain),
+                None => entry["apexName"] == domain.as_str(),
+            };
+
+            if !force && !is_redirect && !is_branch && on_production {
+                anyhow::bail!(
+                    "Refusing to remove {}: it serves production for project {} (pass force: true to override)",
+                    domain,
+                    project_id
+                );
+            }
+
+            client.remove_domain(&project_id, &domain, team_id.as_deref()).await?;
+
+            Ok(serde_json::json!({
+                "removed": true,
+                "project_id": project_id,
+                "domain": domain,
+            }))
+        })
+    }
+
+    /// Verify domain implementation.
+    fn verify_domain(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let domain = Self::get_param_str(&params, "domain")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: domain"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let result = self.runtime.block_on(async move {
+            client
+                .verify_domain(&project_id, &domain, team_id.as_deref())
+                .await
+        })?;
+
+        Ok(result)
+    }
+
+    /// List account domains implementation.
+    ///
+    /// Covers every domain on the account, attached to a project or not.
+    /// `expiring_within_days` keeps only registrations that lapse within that
+    /// many days (including already expired ones).
+    fn list_account_domains(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let expiring_within_days = Self::get_param_i64(&params, "expiring_within_days");
+        if expiring_within_days.is_some_and(|d| d < 0) {
+            anyhow::bail!("expiring_within_days must not be negative");
+        }
+        let max_items = Self::get_max_items(&params)?;
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let collected = self.runtime.block_on(async move {
+            client
+                .list_account_domains_all(max_items, team_id.as_deref())
+                .await
+        });
+        if collected.items.is_empty() {
+            if let Some(error) = &collected.error {
+                anyhow::bail!("Failed to list domains: {}", error);
+            }
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut domains: Vec<&DomainInfo> = collected
+            .items
+            .iter()
+            .filter(|d| match expiring_within_days {
+                Some(days) => d.expires_in_days(now).is_some_and(|left| left <= days),
+                None => true,
+            })
+            .collect();
+        if expiring_within_days.is_some() {
+            domains.sort_by_key(|d| d.expires_at);
+        }
+        let domains: Vec<Value> = domains
+            .into_iter()
+            .map(|d| Self::domain_json(d, now))
+            .collect::<Result<_>>()?;
+
+        let mut result = serde_json::json!({
+            "domains": domains,
+            "count": domains.len(),
+        });
+        if collected.truncated || collected.error.is_some() {
+            result["truncated"] = Value::Bool(true);
+            result["next_cursor"] = serde_json::json!(collected.next);
+            if let Some(error) = collected.error {
+                result["error"] = Value::String(error);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Domain info implementation.
+    fn get_domain_info(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let domain = Self::get_param_str(&params, "domain")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: domain"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("domain {}", domain);
+        let info = self
+            .runtime
+            .block_on(async move { client.get_domain(&domain, team_id.as_deref()).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Self::domain_json(&info, chrono::Utc::now().timestamp_millis())
+    }
+
+    /// A domain with its registrar and days to expiry filled in.
+    fn domain_json(domain: &DomainInfo, now_ms: i64) -> Result<Value> {
+        let mut value = serde_json::to_value(domain)?;
+        value["registrar"] = Value::String(domain.registrar().to_string());
+        value["expires_in_days"] = serde_json::json!(domain.expires_in_days(now_ms));
+        Ok(value)
+    }
+
+    /// Domain status implementation.
+    ///
+    /// Merges the project domain record with its DNS configuration.
+    fn domain_status(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let domain = Self::get_param_str(&params, "domain")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: domain"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let (record, config) = self.runtime.block_on(async move {
+            let record = client
+                .get_project_domain(&project_id, &domain, team_id.as_deref())
+                .await?;
+            let config = client
+                .get_domain_config(&domain, team_id.as_deref())
+                .await?;
+            anyhow::Ok((record, config))
+        })?;
+
+        // Recommended values are ranked lists; flatten them to plain strings.
+        let flatten = |key: &str| -> Vec<Value> {
+            config[key]
+                .as_array()
+                .map(|entries| {
+                    entries
+                        .iter()
+                        .flat_map(|e| match &e["value"] {
+                            Value::Array(values) => values.clone(),
+                            other => vec![other.clone()],
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Ok(serde_json::json!({
+            "domain": record["name"],
+            "verified": record["verified"].as_bool().unwrap_or(false),
+            "misconfigured": config["misconfigured"].as_bool().unwrap_or(false),
+            "configured_by": config["configuredBy"],
+            "expected": {
+                "a": flatten("recommendedIPv4"),
+                "cname": flatten("recommendedCNAME"),
+            },
+            "current": {
+                "a": config.get("aValues").cloned().unwrap_or_else(|| serde_json::json!([])),
+                "cname": config.get("cnames").cloned().unwrap_or_else(|| serde_json::json!([])),
+            },
+            "verification": record.get("verification").cloned().unwrap_or_else(|| serde_json::json!([])),
+        }))
+    }
+
+    /// List aliases implementation.
+    fn list_aliases(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id").map(|s| s.to_string());
+        let deployment_id = Self::get_param_str(&params, "deployment_id").map(|s| s.to_string());
+        let domain = Self::get_param_str(&params, "domain").map(|s| s.to_string());
+        let limit = Self::get_param_i32(&params, "limit", 20);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let aliases = self.runtime.block_on(async move {
+            client
+                .list_aliases(
+                    project_id.as_deref(),
+                    deployment_id.as_deref(),
+                    domain.as_deref(),
+                    Some(limit),
+                    team_id.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "aliases": aliases,
+            "count": aliases.len(),
+        }))
+    }
+
+    /// Assign alias implementation.
+    fn assign_alias(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let alias = Self::get_param_str(&params, "alias")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: alias"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let result = self.runtime.block_on(async move {
+            client
+                .assign_alias(&deployment_id, &alias, team_id.as_deref())
+                .await
+        })?;
+
+        Ok(result)
+    }
+
+    /// Delete alias implementation.
+    fn delete_alias(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let alias_id = Self::get_param_str(&params, "alias")
+            .or_else(|| Self::get_param_str(&params, "alias_id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: alias"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let result = self
+            .runtime
+            .block_on(async move { client.delete_alias(&alias_id, team_id.as_deref()).await })?;
+
+        Ok(result)
+    }
+
+    /// Redeploy implementation.
+    fn redeploy(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let target = Self::get_redeploy_target(&params)?;
+        let skip_build_cache = Self::get_param_bool(&params, "skip_build_cache", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let warning = match target.as_deref() {
+                Some("production") => {
+                    Self::production_branch_warning(&client, &deployment_id, team_id.as_deref())
+                        .await
+                }
+                _ => None,
+            };
+            let mut result = client
+                .redeploy(
+                    &deployment_id,
+                    target.as_deref(),
+                    skip_build_cache,
+                    team_id.as_deref(),
+                )
+                .await?;
+            if let Some(warning) = warning {
+                result["non_production_branch"] = Value::Bool(true);
+                result["warning"] = Value::String(warning);
+            }
+            Ok(result)
+        })
+    }
+
+    /// Read the optional `target` of a redeploy.
+    fn get_redeploy_target(params: &HashMap<String, Value>) -> Result<Option<String>> {
+        match Self::get_param_str(params, "target") {
+            None => Ok(None),
+            Some(t @ ("production" | "preview")) => Ok(Some(t.to_string())),
+            Some(t) => anyhow::bail!("Invalid target {:?}: expected production or preview", t),
+        }
+    }
+
+    /// Vercel allows promoting any deployment to production; flag the case
+    /// where it was built from a branch other than the production branch.
+    ///
+    /// The check only feeds a warning, so a failed lookup is logged and
+    /// skipped rather than stopping the redeploy.
+    async fn production_branch_warning(
+        client: &VercelClient,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Option<String> {
+        Self::check_production_branch(client, deployment_id, team_id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(%deployment_id, "Skipping production branch check: {:#}", e);
+                None
+            })
+    }
+
+    async fn check_production_branch(
+        client: &VercelClient,
+        deployment_id: &str,
+        team_id: Option<&str>,
+    ) -> Result<Option<String>> {
+        let deployment = client.get_deployment(deployment_id, team_id).await?;
+        let Some(branch) = deployment.git_branch() else {
+            return Ok(None);
+        };
+        let Some(project_id) = deployment.project_id.as_deref() else {
+            return Ok(None);
+        };
+        let project = client.get_project(project_id, team_id).await?;
+        let production_branch = project.link.and_then(|l| l.production_branch);
+
+        Ok(match production_branch {
+            Some(production) if production != branch => Some(format!(
+                "Deploying branch {:?} to production; the project's production branch is {:?}",
+                branch, production
+            )),
+            _ => None,
+        })
+    }
+
+    /// Redeploy and wait implementation.
+    ///
+    /// Issues the redeploy, then waits on the new deployment exactly like
+    /// `vercel.wait_for_deployment`.
+    fn redeploy_and_wait(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let deployment_id = Self::get_param_str(&params, "deployment_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: deployment_id"))?
+            .to_string();
+        let timeout_secs = Self::get_param_i64(&params, "timeout_secs").unwrap_or(600);
+        let poll_interval_secs = Self::get_param_i64(&params, "poll_interval_secs").unwrap_or(5);
+        if timeout_secs <= 0 || poll_interval_secs <= 0 {
+            anyhow::bail!("timeout_secs and poll_interval_secs must be positive");
+        }
+        let target = Self::get_redeploy_target(&params)?;
+        let skip_build_cache = Self::get_param_bool(&params, "skip_build_cache", false);
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deployment {}", deployment_id);
+        self.runtime
+            .block_on(async move {
+                let warning = match target.as_deref() {
+                    Some("production") => {
+                        Self::production_branch_warning(&client, &deployment_id, team_id.as_deref())
+                            .await
+                    }
+                    _ => None,
+                };
+                let redeployed = client
+                    .redeploy(
+                        &deployment_id,
+                        target.as_deref(),
+                        skip_build_cache,
+                        team_id.as_deref(),
+                    )
+                    .await?;
+                let new_id = redeployed
+                    .get("id")
+                    .or_else(|| redeployed.get("uid"))
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Redeploy response did not include a deployment id")
+                    })?
+                    .to_string();
+
+                let waited = async {
+                    let wait = client
+                        .wait_for_deployment(
+                            &new_id,
+                            Duration::from_secs(poll_interval_secs as u64),
+                            Duration::from_secs(timeout_secs as u64),
+                            team_id.as_deref(),
+                        )
+                        .await?;
+                    Self::wait_result(&client, wait, team_id.as_deref()).await
+                }
+                .await;
+
+                // The redeploy has started by now, so a failed wait is reported
+                // alongside the new ID rather than as an error a caller would
+                // answer by redeploying again.
+                let mut result = waited
+                    .unwrap_or_else(|e| serde_json::json!({ "wait_error": format!("{:#}", e) }));
+                result["new_deployment_id"] = Value::String(new_id);
+                result["redeployed_from"] = Value::String(deployment_id);
+                if let Some(warning) = warning {
+                    result["non_production_branch"] = Value::Bool(true);
+                    result["warning"] = Value::String(warning);
+                }
+                Ok(result)
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// List deploy hooks implementation.
+    fn list_deploy_hooks(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let hooks = self.runtime.block_on(async move {
+            client
+                .list_deploy_hooks(&project_id, team_id.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "deploy_hooks": hooks,
+            "count": hooks.len(),
+        }))
+    }
+
+    /// Create deploy hook implementation.
+    fn create_deploy_hook(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let name = Self::get_param_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+        let git_ref = Self::get_param_str(&params, "ref")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: ref"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let hook = self.runtime.block_on(async move {
+            client
+                .create_deploy_hook(&project_id, &name, &git_ref, team_id.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::to_value(hook)?)
+    }
+
+    /// Delete deploy hook implementation.
+    fn delete_deploy_hook(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let project_id = Self::get_param_str(&params, "project_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: project_id"))?
+            .to_string();
+        let hook_id = Self::get_param_str(&params, "hook_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: hook_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("deploy hook {}", hook_id);
+        self.runtime
+            .block_on(async {
+                client
+                    .delete_deploy_hook(&project_id, &hook_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "deleted": true,
+            "hook_id": hook_id,
+        }))
+    }
+
+    /// Trigger deploy hook implementation.
+    fn trigger_deploy_hook(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let url = Self::get_param_str(&params, "url")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: url"))?
+            .to_string();
+        if !url.starts_with("https://") {
+            anyhow::bail!("Deploy hook url must be an https:// URL from vercel.deploy_hooks");
+        }
+
+        let client = self.client.clone();
+
+        let result = self
+            .runtime
+            .block_on(async move { client.trigger_deploy_hook(&url).await })?;
+
+        Ok(serde_json::json!({
+            "triggered": true,
+            "job": result.get("job").cloned().unwrap_or(result),
+        }))
+    }
+
+    /// List webhooks implementation.
+    fn list_webhooks(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let webhooks = self
+            .runtime
+            .block_on(async move { client.list_webhooks(team_id.as_deref()).await })?;
+
+        Ok(serde_json::json!({
+            "webhooks": webhooks,
+            "count": webhooks.len(),
+        }))
+    }
+
+    /// Create webhook implementation.
+    ///
+    /// The signing secret is only ever returned here, so it is passed through
+    /// as-is and optionally saved to a private file.
+    fn create_webhook(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let url = Self::get_param_str(&params, "url")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: url"))?
+            .to_string();
+        if !url.starts_with("https://") {
+            anyhow::bail!("Webhook url must be an https:// URL");
+        }
+        let events = Self::get_param_str_list(&params, "events")?;
+        if events.is_empty() {
+            anyhow::bail!("Missing required parameter: events");
+        }
+        let unknown: Vec<&str> = events
+            .iter()
+            .map(|e| e.as_str())
+            .filter(|e| !WEBHOOK_EVENTS.contains(e))
+            .collect();
+        if !unknown.is_empty() {
+            anyhow::bail!(
+                "Unknown webhook event(s): {}. Allowed: {}",
+                unknown.join(", "),
+                WEBHOOK_EVENTS.join(", ")
+            );
+        }
+        let project_ids = Self::get_param_str_list(&params, "project_ids")?;
+        let save_secret_to = Self::get_param_str(&params, "save_secret_to")
+            .map(|p| shellexpand::tilde(p).to_string());
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let webhook = self.runtime.block_on(async move {
+            client
+                .create_webhook(&url, &events, &project_ids, team_id.as_deref())
+                .await
+        })?;
+
+        let mut result = serde_json::to_value(&webhook)?;
+        if let Some(path) = save_secret_to {
+            // The webhook exists either way; report a missing secret or a
+            // failed write instead of losing the webhook and its secret.
+            let saved = match webhook.secret.as_deref() {
+                Some(secret) => Self::write_private_file(&path, &format!("{}\n", secret))
+                    .map_err(|e| format!("Failed to write {}: {}", path, e)),
+                None => Err(format!(
+                    "Webhook {} was created without a secret; nothing written to {}",
+                    webhook.id, path
+                )),
+            };
+            match saved {
+                Ok(()) => result["secret_saved_to"] = Value::String(path),
+                Err(e) => result["secret_save_error"] = Value::String(e),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Delete webhook implementation.
+    fn delete_webhook(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let webhook_id = Self::get_param_str(&params, "webhook_id")
+            .or_else(|| Self::get_param_str(&params, "id"))
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: webhook_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("webhook {}", webhook_id);
+        self.runtime
+            .block_on(async { client.delete_webhook(&webhook_id, team_id.as_deref()).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "deleted": true,
+            "webhook_id": webhook_id,
+        }))
+    }
+
+    /// List secrets implementation.
+    ///
+    /// With `include_usage`, every project's env vars are scanned and each
+    /// secret lists the projects referencing it, with `unused` set when none do.
+    fn list_secrets(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let include_usage = Self::get_param_bool(&params, "include_usage", false);
+        let max_items = Self::get_max_items(&params)?;
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        self.runtime.block_on(async move {
+            let team_id = team_id.as_deref();
+            let secrets = client.list_secrets(team_id).await?;
+            if !include_usage {
+                return Ok(serde_json::json!({
+                    "secrets": secrets,
+                    "count": secrets.len(),
+                }));
+            }
+
+            let projects = client.list_projects_all(max_items, None, team_id).await;
+            if let Some(error) = &projects.error {
+                anyhow::bail!("Failed to list projects for usage check: {}", error);
+            }
+            let scanned = projects.items.len();
+
+            // Env vars of type `secret` hold the secret's uid as their value.
+            let mut usage: HashMap<String, Vec<String>> = HashMap::new();
+            let mut env_lists = futures_util::stream::iter(projects.items)
+                .map(|project| {
+                    let client = client.clone();
+                    async move {
+                        let envs = client
+                            .list_env_vars(&project.id, None, false, team_id)
+                            .await;
+                        (project.name, envs)
+                    }
+                })
+                .buffer_unordered(4);
+            while let Some((project, envs)) = env_lists.next().await {
+                let envs =
+                    envs.with_context(|| format!("Failed to list env vars of {}", project))?;
+                for env in envs {
+                    if env.env_type.as_deref() == Some("secret") {
+                        if let Some(uid) = env.value {
+                            let projects = usage.entry(uid).or_default();
+                            if !projects.contains(&project) {
+                                projects.push(project.clone());
+                            }
+                        }
+                    }
+                }
+            }
+
+            let secrets: Vec<Value> = secrets
+                .into_iter()
+                .map(|secret| {
+                    let mut used_by = usage.remove(&secret.uid).unwrap_or_default();
+                    used_by.sort();
+                    let mut value = serde_json::to_value(&secret)?;
+                    value["unused"] = Value::Bool(used_by.is_empty());
+                    value["used_by"] = serde_json::json!(used_by);
+                    Ok(value)
+                })
+                .collect::<Result<_>>()?;
+            let unused = secrets.iter().filter(|s| s["unused"] == true).count();
+
+            let mut result = serde_json::json!({
+                "secrets": secrets,
+                "count": secrets.len(),
+                "unused_count": unused,
+                "projects_scanned": scanned,
+            });
+            if projects.truncated {
+                result["warning"] = Value::String(format!(
+                    "Only the first {} projects were scanned; raise max_items to check all",
+                    max_items
+                ));
+            }
+            Ok(result)
+        })
+    }
+
+    /// Create secret implementation. The value is never echoed back.
+    fn create_secret(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_param_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?;
+        // `@name` is how env vars refer to secrets; accept it as-is.
+        let name = name.strip_prefix('@').unwrap_or(name).to_string();
+        Self::check_secret_name(&name)?;
+        let value = Self::get_param_str(&params, "value")
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: value"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let secret = self.runtime.block_on(async move {
+            client
+                .create_secret(&name, &value, team_id.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::to_value(secret)?)
+    }
+
+    /// Check a secret name against Vercel's rules: lowercase letters, digits,
+    /// and dashes, at most 100 characters.
+    fn check_secret_name(name: &str) -> Result<()> {
+        if name.is_empty() || name.len() > 100 {
+            anyhow::bail!("Secret name must be 1-100 characters (got {})", name.len());
+        }
+        if let Some(c) = name
+            .chars()
+            .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-'))
+        {
+            anyhow::bail!(
+                "Invalid secret name {:?}: {:?} not allowed (use lowercase letters, digits, and dashes)",
+                name,
+                c
+            );
+        }
+        Ok(())
+    }
+
+    /// Delete secret implementation.
+    fn delete_secret(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_param_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?;
+        let name = name.strip_prefix('@').unwrap_or(name).to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("secret {}", name);
+        let secret = self
+            .runtime
+            .block_on(async { client.delete_secret(&name, team_id.as_deref()).await })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "deleted": true,
+            "uid": secret.uid,
+            "name": secret.name,
+        }))
+    }
+
+    /// List Edge Configs implementation.
+    fn list_edge_configs(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let configs = self
+            .runtime
+            .block_on(async move { client.list_edge_configs(team_id.as_deref()).await })?;
+
+        Ok(serde_json::json!({
+            "edge_configs": configs,
+            "count": configs.len(),
+        }))
+    }
+
+    /// List Edge Config items implementation.
+    fn get_edge_config_items(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let config_id = Self::get_param_str(&params, "config_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: config_id"))?
+            .to_string();
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("Edge Config {}", config_id);
+        let items = self
+            .runtime
+            .block_on(async move {
+                client
+                    .get_edge_config_items(&config_id, team_id.as_deref())
+                    .await
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))?;
+
+        Ok(serde_json::json!({
+            "items": items,
+            "count": items.len(),
+        }))
+    }
+
+    /// Set Edge Config items implementation.
+    ///
+    /// `items` are upserted and `delete_keys` removed in a single write. A
+    /// 409 from a concurrent write is retried once, sending the same
+    /// operations: upserts and deletes don't depend on the current items, so
+    /// there is no fresh state to merge in.
+    fn set_edge_config_items(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let config_id = Self::get_param_str(&params, "config_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: config_id"))?
+            .to_string();
+        let items = match params.get("items") {
+            None | Some(Value::Null) => serde_json::Map::new(),
+            Some(Value::Object(items)) => items.clone(),
+            Some(_) => anyhow::bail!("items must be an object of key/value pairs"),
+        };
+        let delete_keys = Self::get_param_str_list(&params, "delete_keys")?;
+        if items.is_empty() && delete_keys.is_empty() {
+            anyhow::bail!("Nothing to change: pass items and/or delete_keys");
+        }
+        if let Some(key) = delete_keys.iter().find(|k| items.contains_key(*k)) {
+            anyhow::bail!("Key {:?} is in both items and delete_keys", key);
+        }
+
+        let mut operations: Vec<Value> = items
+            .iter()
+            .map(|(key, value)| {
+                serde_json::json!({
+                    "operation": "upsert",
+                    "key": key,
+                    "value": value,
+                })
+            })
+            .collect();
+        operations.extend(delete_keys.iter().map(|key| {
+            serde_json::json!({
+                "operation": "delete",
+                "key": key,
+            })
+        }));
+
+        let team_id = Self::get_team_id(&params);
+        let client = self.client.clone();
+
+        let subject = format!("Edge Config {}", config_id);
+        let (upserted, deleted) = (items.len(), delete_keys.len());
+        self.runtime
+            .block_on(async move {
+                let team_id = team_id.as_deref();
+                let mut retried = false;
+                if let Err(e) = client
+                    .patch_edge_config_items(&config_id, &operations, team_id)
+                    .await
+                {
+                    let conflict = matches!(
+                        e.downcast_ref::<VercelApiError>(),
+                        Some(VercelApiError::Response { status, .. })
+                            if *status == reqwest::StatusCode::CONFLICT
+                    );
+                    if !conflict {
+                        return Err(e);
+                    }
+                    tracing::warn!(%config_id, "Edge Config write conflicted, retrying once");
+                    client
+                        .patch_edge_config_items(&config_id, &operations, team_id)
+                        .await?;
+                    retried = true;
+                }
+
+                let config = client.get_edge_config(&config_id, team_id).await?;
+                Ok(serde_json::json!({
+                    "config_id": config_id,
+                    "upserted": upserted,
+                    "deleted": deleted,
+                    "digest": config.digest,
+                    "retried": retried,
+                }))
+            })
+            .map_err(|e| Self::explain_api_error(e, &subject))
+    }
+
+    /// Call the handler for `method`.
+    fn route(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
+        match method {
+            "health" => self.health(),
+            "projects" | "vercel.projects" => self.list_projects(params),
+            "project" | "vercel.project" => self.get_project(params),
+            "project_members" | "vercel.project_members" => self.list_project_members(params),
+            "project_member_add" | "vercel.project_member_add" => self.add_project_member(params),
+            "project_member_remove" | "vercel.project_member_remove" => {
+                self.remove_project_member(params)
+            }
+            "git_info" | "vercel.git_info" => self.get_git_info(params),
+            "purge_cache" | "vercel.purge_cache" => self.purge_cache(params),
+            "crons" | "vercel.crons" => self.list_crons(params),
+            "cron_toggle" | "vercel.cron_toggle" => self.toggle_crons(params),
+            "protection" | "vercel.protection" => self.get_protection(params),
+            "protection_set" | "vercel.protection_set" => self.set_protection(params),
+            "bypass_secret" | "vercel.bypass_secret" => self.bypass_secret(params),
+            "project_overview" | "vercel.project_overview" => self.project_overview(params),
+            "create_project" | "vercel.create_project" => self.create_project(params),
+            "update_project" | "vercel.update_project" => self.update_project(params),
+            "pause_project" | "vercel.pause_project" => self.set_project_paused(params, true),
+            "unpause_project" | "vercel.unpause_project" => self.set_project_paused(params, false),
+            "delete_project" | "vercel.delete_project" => self.delete_project(params),
+            "deployments" | "vercel.deployments" => self.list_deployments(params),
+            "latest_deployment" | "vercel.latest_deployment" => self.latest_deployment(params),
+            "deployment" | "vercel.deployment" => self.get_deployment(params),
+            "cancel" | "vercel.cancel" => self.cancel_deployment(params),
+            "delete_deployment" | "vercel.delete_deployment" => self.delete_deployment(params),
+            "rollback" | "vercel.rollback" => self.rollback(params),
+            "watch" | "vercel.watch" => self.watch_deployment(params),
+            "wait_for_deployment" | "vercel.wait_for_deployment" => {
+                self.wait_for_deployment(params)
+            }
+            "logs" | "vercel.logs" => self.get_deployment_logs(params),
+            "build_summary" | "vercel.build_summary" => self.build_summary(params),
+            "functions" | "vercel.functions" => self.list_functions(params),
+            "checks" | "vercel.checks" => self.get_deployment_checks(params),
+            "check" | "vercel.check" => self.get_check(params),
+            "check_rerun" | "vercel.check_rerun" => self.rerun_check(params),
+            "files" | "vercel.files" => self.list_deployment_files(params),
+            "file" | "vercel.file" => self.get_deployment_file(params),
+            "stream_logs" | "vercel.stream_logs" => self.stream_logs(params),
+            "user" | "vercel.user" | "get_user" | "vercel.get_user" => self.get_user(params),
+            "teams" | "vercel.teams" => self.list_teams(params),
+            "team" | "vercel.team" => self.get_team(params),
+            "members" | "vercel.members" => self.list_team_members(params),
+            "member_invite" | "vercel.member_invite" => self.invite_team_member(params),
+            "member_role" | "vercel.member_role" => self.update_team_member_role(params),
+            "member_remove" | "vercel.member_remove" => self.remove_team_member(params),
+            "env_vars" | "vercel.env_vars" => self.list_env_vars(params),
+            "set_env" | "vercel.set_env" => self.set_env_var(params),
+            "set_env_bulk" | "vercel.set_env_bulk" | "bulk_set_env" | "vercel.bulk_set_env" => {
+                self.bulk_set_env_vars(params)
+            }
+            "env_import" | "vercel.env_import" => self.env_import(params),
+            "env_export" | "vercel.env_export" => self.env_export(params),
+            "env_diff" | "vercel.env_diff" => self.env_diff(params),
+            "env_copy" | "vercel.env_copy" | "copy_env_vars" | "vercel.copy_env_vars" => {
+                self.copy_env_vars(params)
+            }
+            "get_env" | "vercel.get_env" => self.get_env_var(params),
+            "delete_env" | "vercel.delete_env" => self.delete_env_var(params),
+            "domains" | "vercel.domains" => self.list_domains(params),
+            "add_domain" | "vercel.add_domain" | "domain_add" | "vercel.domain_add" => {
+                self.add_domain(params)
+            }
+            "remove_domain" | "vercel.remove_domain" | "domain_remove" | "vercel.domain_remove" => {
+                self.remove_domain(params)
+            }
+            "verify_domain" | "vercel.verify_domain" | "domain_verify" | "vercel.domain_verify" => {
+                self.verify_domain(params)
+            }
+            "domain_status" | "vercel.domain_status" => self.domain_status(params),
+            "domain_move" | "vercel.domain_move" => self.move_domain(params),
+            "account_domains" | "vercel.account_domains" => self.list_account_domains(params),
+            "domain_info" | "vercel.domain_info" => self.get_domain_info(params),
+            "aliases" | "vercel.aliases" => self.list_aliases(params),
+            "alias_set" | "vercel.alias_set" | "assign_alias" | "vercel.assign_alias" => {
+                self.assign_alias(params)
+            }
+            "alias_delete" | "vercel.alias_delete" | "delete_alias" | "vercel.delete_alias" => {
+                self.delete_alias(params)
+            }
+            "redeploy" | "vercel.redeploy" => self.redeploy(params),
+            "redeploy_and_wait" | "vercel.redeploy_and_wait" => self.redeploy_and_wait(params),
+            "deploy_hooks"
+            | "vercel.deploy_hooks"
+            | "list_deploy_hooks"
+            | "vercel.list_deploy_hooks" => self.list_deploy_hooks(params),
+            "create_deploy_hook" | "vercel.create_deploy_hook" => self.create_deploy_hook(params),
+            "delete_deploy_hook" | "vercel.delete_deploy_hook" => self.delete_deploy_hook(params),
+            "trigger_deploy_hook" | "vercel.trigger_deploy_hook" => {
+                self.trigger_deploy_hook(params)
+            }
+            "edge_configs" | "vercel.edge_configs" => self.list_edge_configs(params),
+            "edge_config_items" | "vercel.edge_config_items" => self.get_edge_config_items(params),
+            "edge_config_set" | "vercel.edge_config_set" => self.set_edge_config_items(params),
+            "webhooks" | "vercel.webhooks" => self.list_webhooks(params),
+            "webhook_create" | "vercel.webhook_create" => self.create_webhook(params),
+            "webhook_delete" | "vercel.webhook_delete" => self.delete_webhook(params),
+            "secrets" | "vercel.secrets" => self.list_secrets(params),
+            "secret_create" | "vercel.secret_create" => self.create_secret(params),
+            "secret_delete" | "vercel.secret_delete" => self.delete_secret(params),
+            "reload" | "vercel.reload" => self.reload(),
+            _ => Err(UnknownMethod(method.to_string()).into()),
+        }
+    }
+}
+
+impl FgpService for VercelService {
+    fn name(&self) -> &str {
+        "vercel"
+    }
+
+    fn version(&self) -> &str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    fn dispatch(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
+        #[cfg(feature = "prometheus")]
+        let _active = crate::prometheus::ActiveRequest::start();
+        let start = Instant::now();
+        let result = self.route(method, params);
+        let elapsed = start.elapsed();
+
+        // Unknown names would otherwise take up slots meant for real methods.
+        if result.as_ref().is_err_and(|e| e.is::<UnknownMethod>()) {
+            return result;
+        }
+        let method = method.strip_prefix("vercel.").unwrap_or(method);
+        if self.metrics.record(method, elapsed) {
+            #[cfg(feature = "prometheus")]
+            crate::prometheus::record(method, result.is_ok(), elapsed);
+        }
+        result
+    }
+
+    fn method_list(&self) -> Vec<MethodInfo> {
+        vec![
+            MethodInfo {
+                name: "vercel.projects".into(),
+                description: "List all Vercel projects".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "limit".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(20)),
+                    },
+                    ParamInfo {
+                        name: "until".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "next".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "all".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "max_items".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(1000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.project".into(),
+                description: "Get a specific project by ID or name".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.project_members".into(),
+                description: "List a project's members (pass next_cursor as until, or all: true, to page through)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "limit".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(20)),
+                    },
+                    ParamInfo {
+                        name: "until".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "next".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "all".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "max_items".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(1000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.project_member_add".into(),
+                description: "Add a team member to a project by uid or email with a project role (ADMIN, PROJECT_DEVELOPER, PROJECT_VIEWER)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "uid".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "email".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "role".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("PROJECT_DEVELOPER")),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.project_member_remove".into(),
+                description: "Remove a member from a project by uid or email".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "uid".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "email".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.git_info".into(),
+                description: "Get the Git repository a project is connected to (provider, repo, org, production branch)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.purge_cache".into(),
+                description: "Purge a project's cache (kind: data); a rate-limited purge reports retry_after_secs".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "kind".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("data")),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.crons".into(),
+                description: "List a project's cron jobs with their previous and next fire times (UTC)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.cron_toggle".into(),
+                description: "Enable or disable a project's cron jobs".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "enabled".into(),
+                        param_type: "boolean".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.protection".into(),
+                description: "Get a project's deployment protection (Vercel Authentication, password, trusted IPs)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.protection_set".into(),
+                description: "Change deployment protection; pass \"off\" to disable a setting. The password is never returned".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "sso".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "password_protection".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "password".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "trusted_ips".into(),
+                        param_type: "array".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "trusted_ips_deployment_type".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("all")),
+                    },
+                    ParamInfo {
+                        name: "trusted_ips_mode".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("additional")),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.bypass_secret".into(),
+                description: "Get, generate, or revoke a project's protection bypass secret for automation. generate returns the new secret once and invalidates the previous one".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "action".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("get")),
+                    },
+                    ParamInfo {
+                        name: "secret".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.project_overview".into(),
+                description: "Summarize a project: details, latest production and preview deployments, domains, and env var counts per target".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.create_project".into(),
+                description: "Create a new project".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "name".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "framework".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "git_repository".into(),
+                        param_type: "object".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "skip_git".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "build_command".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "dev_command".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "install_command".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "output_directory".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "root_directory".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.update_project".into(),
+                description: "Update project settings".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "name".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "framework".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "build_command".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "dev_command".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "install_command".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "output_directory".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "root_directory".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "node_version".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "serverless_function_region".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.pause_project".into(),
+                description: "Pause a project so it stops serving traffic".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.unpause_project".into(),
+                description: "Resume serving traffic for a paused project".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.delete_project".into(),
+                description: "Delete a project (confirm must equal the project name)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "confirm".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.deployments".into(),
+                description: "List deployments (optionally filtered by project)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "limit".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(20)),
+                    },
+                    ParamInfo {
+                        name: "since".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "until".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "state".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "branch".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "next".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "all".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "max_items".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(1000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.latest_deployment".into(),
+                description: "Get the newest deployment for a project matching target (default production) and state (default READY)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("production")),
+                    },
+                    ParamInfo {
+                        name: "state".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("READY")),
+                    },
+                    ParamInfo {
+                        name: "include_domains".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.deployment".into(),
+                description: "Get a specific deployment by ID or URL".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.cancel".into(),
+                description: "Cancel an in-progress deployment".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.delete_deployment".into(),
+                description: "Delete a deployment by ID or URL (requires confirm: true)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "url".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "confirm".into(),
+                        param_type: "boolean".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "force".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.rollback".into(),
+                description: "Roll production back to a previous deployment".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.watch".into(),
+                description: "Wait for a deployment to reach READY, ERROR, or CANCELED".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "poll_interval_ms".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(5000)),
+                    },
+                    ParamInfo {
+                        name: "timeout_ms".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(300000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.wait_for_deployment".into(),
+                description: "Wait for a deployment (ID or URL) to finish, returning the final state, state transitions, and build logs on ERROR".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "timeout_secs".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(600)),
+                    },
+                    ParamInfo {
+                        name: "poll_interval_secs".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(5)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.logs".into(),
+                description: "Get deployment logs/events; follow: true polls until the build finishes".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "since".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "follow".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "max_wait_secs".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(600)),
+                    },
+                    ParamInfo {
+                        name: "poll_interval_secs".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(5)),
+                    },
+                    ParamInfo {
+                        name: "event_type".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "grep".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "regex".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "tail".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.build_summary".into(),
+                description: "Summarize a deployment's build: queued and build time, cache restore, exit code, warning and error counts".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.functions".into(),
+                description: "List the serverless functions in a deployment".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.checks".into(),
+                description: "List a deployment's checks (CI/CD gates) with all_passed and any_blocking_failed rollups".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.check".into(),
+                description: "Get a single deployment check".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "check_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.check_rerun".into(),
+                description: "Rerun a deployment check".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "check_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.files".into(),
+                description: "List a deployment's source file tree".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.file".into(),
+                description: "Get a deployment file's contents by file_id or path (truncated past max_bytes)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "file_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "path".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "max_bytes".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(1048576)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.stream_logs".into(),
+                description: "Follow deployment logs until the build finishes".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "timeout_ms".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(600000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.user".into(),
+                description: "Get current user info (alias: vercel.get_user)".into(),
+                params: vec![ParamInfo {
+                    name: "include_teams".into(),
+                    param_type: "boolean".into(),
+                    required: false,
+                    default: Some(serde_json::json!(false)),
+                }],
+            },
+            MethodInfo {
+                name: "vercel.teams".into(),
+                description: "List teams the current user belongs to".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "limit".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(20)),
+                    },
+                    ParamInfo {
+                        name: "until".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                ],
+            },
+            MethodInfo {
+                name: "vercel.team".into(),
+                description: "Get a team by ID, including your membership role".into(),
+                params: vec![ParamInfo {
+                    name: "team_id".into(),
+                    param_type: "string".into(),
+                    required: true,
+                    default: None,
+                }],
+            },
+            MethodInfo {
+                name: "vercel.members".into(),
+                description: "List a team's members (defaults to the daemon's team; pass next_cursor as until, or all: true, to page through)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "limit".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(20)),
+                    },
+                    ParamInfo {
+                        name: "until".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "next".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "all".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "max_items".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(1000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.member_invite".into(),
+                description: "Invite someone to a team by email with a role (OWNER, MEMBER, DEVELOPER, VIEWER, BILLING, CONTRIBUTOR)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "email".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "role".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.member_role".into(),
+                description: "Change a team member's role; the last owner cannot be demoted".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "uid".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "role".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.member_remove".into(),
+                description: "Remove a member from a team; the last owner cannot be removed".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "uid".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.env_vars".into(),
+                description: "List environment variables for a project".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "key_prefix".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "type".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "reveal".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "git_branch".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.set_env".into(),
+                description: "Set an environment variable".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "key".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "value".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "array".into(),
+                        required: false,
+                        default: Some(serde_json::json!(["production", "preview", "development"])),
+                    },
+                    ParamInfo {
+                        name: "type".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("encrypted")),
+                    },
+                    ParamInfo {
+                        name: "git_branch".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.set_env_bulk".into(),
+                description: "Create or update several environment variables in one call (alias: vercel.bulk_set_env)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "vars".into(),
+                        param_type: "array".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "upsert".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(true)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.env_import".into(),
+                description: "Import environment variables from a dotenv file or string".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "path".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "content".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "array".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "overwrite".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "type".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: Some(serde_json::json!("encrypted")),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.env_export".into(),
+                description: "Export environment variables as a dotenv document".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "path".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.env_copy".into(),
+                description: "Copy environment variables from one project to another (alias: vercel.copy_env_vars)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "source_project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "dest_project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "key_prefix".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "overwrite".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "dry_run".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.env_diff".into(),
+                description: "Diff environment variables between two targets or two projects".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target_a".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target_b".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "project_a".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "project_b".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "reveal".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.get_env".into(),
+                description: "Get a single environment variable with its decrypted value".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "env_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "key".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.delete_env".into(),
+                description: "Delete an environment variable by id, or by key and target".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "env_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "key".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.domains".into(),
+                description: "List domains for a project".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.add_domain".into(),
+                description: "Add a custom domain to a project (alias: vercel.domain_add)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "domain".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "redirect".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "redirect_status_code".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "git_branch".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.remove_domain".into(),
+                description: "Remove a domain from a project (alias: vercel.domain_remove)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "domain".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "force".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.verify_domain".into(),
+                description:
+                    "Trigger verification of a project domain (alias: vercel.domain_verify)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "domain".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.domain_move".into(),
+                description: "Move a domain between projects: add to the destination, check it is verified there, then remove from the source (rolling back on failure)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "domain".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "from_project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "to_project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "force".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.domain_status".into(),
+                description: "Get verification and DNS configuration status for a domain".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "project_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "domain".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.account_domains".into(),
+                description: "List every domain on the account with registrar, nameservers, expiry, and auto-renew; expiring_within_days keeps registrations about to lapse".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "expiring_within_days".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "max_items".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(1000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.domain_info".into(),
+                description: "Get a domain's registrar, nameservers, expiry, and auto-renew status".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "domain".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.aliases".into(),
+                description: "List aliases for a deployment or project".into(),
                 params: vec![
                     ParamInfo {
                         name: "project_id".into(),
@@ -290,60 +6060,126 @@ impl FgpService for VercelService {
                         required: false,
                         default: None,
                     },
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "domain".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
                     ParamInfo {
                         name: "limit".into(),
                         param_type: "integer".into(),
                         required: false,
                         default: Some(serde_json::json!(20)),
                     },
+                    Self::team_id_param(),
                 ],
             },
             MethodInfo {
-                name: "vercel.deployment".into(),
-                description: "Get a specific deployment by ID".into(),
-                params: vec![ParamInfo {
-                    name: "deployment_id".into(),
-                    param_type: "string".into(),
-                    required: true,
-                    default: None,
-                }],
+                name: "vercel.alias_set".into(),
+                description: "Assign an alias to a deployment (alias: vercel.assign_alias)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "alias".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
             },
             MethodInfo {
-                name: "vercel.logs".into(),
-                description: "Get deployment logs/events".into(),
-                params: vec![ParamInfo {
-                    name: "deployment_id".into(),
-                    param_type: "string".into(),
-                    required: true,
-                    default: None,
-                }],
+                name: "vercel.alias_delete".into(),
+                description: "Delete an alias by ID or hostname (alias: vercel.delete_alias)"
+                    .into(),
+                params: vec![
+                    ParamInfo {
+                        name: "alias".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
             },
             MethodInfo {
-                name: "vercel.user".into(),
-                description: "Get current user info".into(),
-                params: vec![],
+                name: "vercel.redeploy".into(),
+                description: "Redeploy a deployment".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "deployment_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "target".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "skip_build_cache".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
+                ],
             },
             MethodInfo {
-                name: "vercel.env_vars".into(),
-                description: "List environment variables for a project".into(),
+                name: "vercel.redeploy_and_wait".into(),
+                description: "Redeploy a deployment and wait for the new build to finish, returning its final state, build duration, and build logs on ERROR".into(),
                 params: vec![
                     ParamInfo {
-                        name: "project_id".into(),
+                        name: "deployment_id".into(),
                         param_type: "string".into(),
                         required: true,
                         default: None,
                     },
+                    ParamInfo {
+                        name: "timeout_secs".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(600)),
+                    },
+                    ParamInfo {
+                        name: "poll_interval_secs".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(5)),
+                    },
                     ParamInfo {
                         name: "target".into(),
                         param_type: "string".into(),
                         required: false,
                         default: None,
                     },
+                    ParamInfo {
+                        name: "skip_build_cache".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    Self::team_id_param(),
                 ],
             },
             MethodInfo {
-                name: "vercel.set_env".into(),
-                description: "Set an environment variable".into(),
+                name: "vercel.deploy_hooks".into(),
+                description: "List a project's deploy hooks (alias: vercel.list_deploy_hooks)"
+                    .into(),
                 params: vec![
                     ParamInfo {
                         name: "project_id".into(),
@@ -351,51 +6187,214 @@ impl FgpService for VercelService {
                         required: true,
                         default: None,
                     },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.create_deploy_hook".into(),
+                description: "Create a deploy hook for a git branch or tag".into(),
+                params: vec![
                     ParamInfo {
-                        name: "key".into(),
+                        name: "project_id".into(),
                         param_type: "string".into(),
                         required: true,
                         default: None,
                     },
                     ParamInfo {
-                        name: "value".into(),
+                        name: "name".into(),
                         param_type: "string".into(),
                         required: true,
                         default: None,
                     },
                     ParamInfo {
-                        name: "target".into(),
-                        param_type: "array".into(),
-                        required: false,
-                        default: Some(serde_json::json!(["production", "preview", "development"])),
+                        name: "ref".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
                     },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.delete_deploy_hook".into(),
+                description: "Delete a deploy hook".into(),
+                params: vec![
                     ParamInfo {
-                        name: "type".into(),
+                        name: "project_id".into(),
                         param_type: "string".into(),
-                        required: false,
-                        default: Some(serde_json::json!("encrypted")),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "hook_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
                     },
+                    Self::team_id_param(),
                 ],
             },
             MethodInfo {
-                name: "vercel.domains".into(),
-                description: "List domains for a project".into(),
+                name: "vercel.trigger_deploy_hook".into(),
+                description: "Trigger a deployment by calling a deploy hook URL".into(),
                 params: vec![ParamInfo {
-                    name: "project_id".into(),
+                    name: "url".into(),
                     param_type: "string".into(),
                     required: true,
                     default: None,
                 }],
             },
             MethodInfo {
-                name: "vercel.redeploy".into(),
-                description: "Redeploy a deployment".into(),
-                params: vec![ParamInfo {
-                    name: "deployment_id".into(),
-                    param_type: "string".into(),
-                    required: true,
-                    default: None,
-                }],
+                name: "vercel.edge_configs".into(),
+                description: "List Edge Config stores".into(),
+                params: vec![
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.edge_config_items".into(),
+                description: "List the items of an Edge Config".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "config_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.edge_config_set".into(),
+                description: "Upsert and delete Edge Config items; returns the new digest".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "config_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "items".into(),
+                        param_type: "object".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "delete_keys".into(),
+                        param_type: "array".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.webhooks".into(),
+                description: "List webhooks".into(),
+                params: vec![
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.webhook_create".into(),
+                description: "Create a webhook; the signing secret is only returned once (save_secret_to writes it to a 0600 file)".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "url".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "events".into(),
+                        param_type: "array".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "project_ids".into(),
+                        param_type: "array".into(),
+                        required: false,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "save_secret_to".into(),
+                        param_type: "string".into(),
+                        required: false,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.webhook_delete".into(),
+                description: "Delete a webhook".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "webhook_id".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.secrets".into(),
+                description: "List legacy secrets; include_usage flags secrets no project env var references".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "include_usage".into(),
+                        param_type: "boolean".into(),
+                        required: false,
+                        default: Some(serde_json::json!(false)),
+                    },
+                    ParamInfo {
+                        name: "max_items".into(),
+                        param_type: "integer".into(),
+                        required: false,
+                        default: Some(serde_json::json!(1000)),
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.secret_create".into(),
+                description: "Create a legacy secret (name: lowercase letters, digits, dashes). The value is never returned".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "name".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    ParamInfo {
+                        name: "value".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.secret_delete".into(),
+                description: "Delete a legacy secret by name or ID".into(),
+                params: vec![
+                    ParamInfo {
+                        name: "name".into(),
+                        param_type: "string".into(),
+                        required: true,
+                        default: None,
+                    },
+                    Self::team_id_param(),
+                ],
+            },
+            MethodInfo {
+                name: "vercel.reload".into(),
+                description: "Re-read the access token without restarting the daemon".into(),
+                params: vec![],
             },
         ]
     }
@@ -448,6 +6447,39 @@ impl FgpService for VercelService {
             }
         }
 
+        let rate_limit = self.client.rate_limit_state();
+        if let Some(remaining) = rate_limit.remaining {
+            let reset = rate_limit
+                .reset
+                .map(|r| r.to_string())
+                .unwrap_or_else(|| "unknown".into());
+            let summary = format!(
+                "{}/{} requests remaining, resets at {}",
+                remaining,
+                rate_limit
+                    .limit
+                    .map(|l| l.to_string())
+                    .unwrap_or_else(|| "?".into()),
+                reset
+            );
+
+            let status = if remaining == 0 {
+                HealthStatus::unhealthy(format!("Rate limit exhausted: {}", summary))
+            } else {
+                let mut status = HealthStatus::healthy();
+                status.message = Some(summary);
+                status
+            };
+            checks.insert("rate_limit".into(), status);
+        }
+
+        let metrics = self.metrics.snapshot();
+        if !metrics.is_empty() {
+            let mut status = HealthStatus::healthy();
+            status.message = Some(serde_json::to_string(&metrics).unwrap_or_default());
+            checks.insert("metrics".into(), status);
+        }
+
         checks
     }
 }